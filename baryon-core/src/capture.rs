@@ -0,0 +1,64 @@
+use std::num::NonZeroU32;
+
+impl super::Context {
+    /// Render a frame offscreen and read it back into an image.
+    ///
+    /// The pass has to be created for [`super::TargetInfo::offscreen`].
+    pub async fn capture<P: super::Pass>(
+        &mut self,
+        pass: &mut P,
+        scene: &super::Scene,
+        camera: &super::Camera,
+        size: wgpu::Extent3d,
+    ) -> image::RgbaImage {
+        let texture = self.render_to_texture(pass, scene, camera, size);
+
+        let row_size = size.width * 4;
+        let align = wgpu::COPY_BYTES_PER_ROW_ALIGNMENT;
+        let padded_row_size = (row_size + align - 1) / align * align;
+        let buffer = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("capture"),
+            size: (padded_row_size * size.height) as wgpu::BufferAddress,
+            usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let mut encoder = self
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+        encoder.copy_texture_to_buffer(
+            texture.as_image_copy(),
+            wgpu::ImageCopyBuffer {
+                buffer: &buffer,
+                layout: wgpu::ImageDataLayout {
+                    offset: 0,
+                    bytes_per_row: NonZeroU32::new(padded_row_size),
+                    rows_per_image: None,
+                },
+            },
+            size,
+        );
+        self.queue.submit(Some(encoder.finish()));
+
+        let slice = buffer.slice(..);
+        let mapping = slice.map_async(wgpu::MapMode::Read);
+        self.device.poll(wgpu::Maintain::Wait);
+        mapping.await.expect("Unable to read the capture back");
+
+        let mut pixels = Vec::with_capacity((row_size * size.height) as usize);
+        for row in slice.get_mapped_range().chunks(padded_row_size as usize) {
+            pixels.extend_from_slice(&row[..row_size as usize]);
+        }
+        buffer.unmap();
+
+        match Self::OFFSCREEN_FORMAT {
+            wgpu::TextureFormat::Bgra8Unorm | wgpu::TextureFormat::Bgra8UnormSrgb => {
+                for texel in pixels.chunks_mut(4) {
+                    texel.swap(0, 2);
+                }
+            }
+            _ => {}
+        }
+        image::RgbaImage::from_raw(size.width, size.height, pixels).unwrap()
+    }
+}
@@ -0,0 +1,77 @@
+use super::Color;
+
+/// A piecewise-linear mapping from scalars to colors.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Gradient {
+    stops: Box<[(f32, Color)]>,
+}
+
+const VIRIDIS: [u32; 11] = [
+    0xFF440154, 0xFF482475, 0xFF414487, 0xFF355F8D, 0xFF2A788E, 0xFF21918C, 0xFF22A884, 0xFF44BF70,
+    0xFF7AD151, 0xFFBDDF26, 0xFFFDE725,
+];
+
+// Sampled from the polynomial approximation of Turbo.
+const TURBO: [u32; 11] = [
+    0xFF23171B, 0xFF4958DD, 0xFF2F9EF5, 0xFF27D7C3, 0xFF4EF983, 0xFF96FA50, 0xFFDFDC32, 0xFFFFA323,
+    0xFFF45C17, 0xFFB82008, 0xFF900D00,
+];
+
+impl Gradient {
+    /// Create from a list of `(position, color)` stops, in any order.
+    ///
+    /// Panics if there are no stops, or if a position is NaN.
+    pub fn new(stops: impl IntoIterator<Item = (f32, Color)>) -> Self {
+        let mut stops = stops.into_iter().collect::<Vec<_>>();
+        assert!(!stops.is_empty(), "Gradient needs at least one stop");
+        stops.sort_by(|a, b| a.0.partial_cmp(&b.0).expect("NaN gradient stop"));
+        Self {
+            stops: stops.into_boxed_slice(),
+        }
+    }
+
+    fn uniform(colors: &[u32]) -> Self {
+        let step = 1.0 / (colors.len() - 1) as f32;
+        Self::new(
+            colors
+                .iter()
+                .enumerate()
+                .map(|(i, &value)| (i as f32 * step, Color(value))),
+        )
+    }
+
+    /// Black to white over `[0, 1]`.
+    pub fn grayscale() -> Self {
+        Self::new(vec![(0.0, Color::BLACK_OPAQUE), (1.0, Color::WHITE)])
+    }
+
+    /// Perceptually uniform "viridis" palette over `[0, 1]`.
+    pub fn viridis() -> Self {
+        Self::uniform(&VIRIDIS)
+    }
+
+    /// Rainbow-like "turbo" palette over `[0, 1]`.
+    pub fn turbo() -> Self {
+        Self::uniform(&TURBO)
+    }
+
+    pub fn stops(&self) -> &[(f32, Color)] {
+        &self.stops
+    }
+
+    /// Sample the color at position `t`.
+    ///
+    /// Positions outside of the stop range are clamped. The stops are
+    /// interpolated as stored, matching how palettes are usually specified.
+    pub fn sample(&self, t: f32) -> Color {
+        let index = self.stops.partition_point(|&(pos, _)| pos <= t);
+        if index == 0 {
+            return self.stops[0].1;
+        }
+        let (pos1, color1) = self.stops[index - 1];
+        match self.stops.get(index) {
+            Some(&(pos2, color2)) => color1.lerp_srgb(color2, (t - pos1) / (pos2 - pos1)),
+            None => color1,
+        }
+    }
+}
@@ -0,0 +1,147 @@
+use super::{linear_to_srgb, srgb_to_linear, Color};
+use std::ops;
+
+/// Floating-point color in linear space, with straight (non-premultiplied) alpha.
+///
+/// Unlike [`Color`], the channels are not limited to `[0, 1]`,
+/// which allows expressing HDR values for lighting math.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct LinearColor {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+    pub a: f32,
+}
+
+unsafe impl bytemuck::Zeroable for LinearColor {}
+unsafe impl bytemuck::Pod for LinearColor {}
+
+impl LinearColor {
+    pub const BLACK: Self = Self::new(0.0, 0.0, 0.0, 1.0);
+    pub const WHITE: Self = Self::new(1.0, 1.0, 1.0, 1.0);
+    pub const TRANSPARENT: Self = Self::new(0.0, 0.0, 0.0, 0.0);
+
+    pub const fn new(r: f32, g: f32, b: f32, a: f32) -> Self {
+        Self { r, g, b, a }
+    }
+
+    /// Interpolate all the channels towards `other` by `t`.
+    ///
+    /// Unlike [`Color::lerp`], `t` is not clamped.
+    pub fn lerp(self, other: Self, t: f32) -> Self {
+        self + (other - self) * t
+    }
+
+    /// Clamp all the channels into `[0, 1]`.
+    pub fn clamp(self) -> Self {
+        self.map(|v| v.clamp(0.0, 1.0))
+    }
+
+    pub fn into_vec4(self) -> [f32; 4] {
+        [self.r, self.g, self.b, self.a]
+    }
+
+    fn map(self, fun: impl Fn(f32) -> f32) -> Self {
+        Self::new(fun(self.r), fun(self.g), fun(self.b), fun(self.a))
+    }
+
+    fn zip(self, other: Self, fun: impl Fn(f32, f32) -> f32) -> Self {
+        Self::new(
+            fun(self.r, other.r),
+            fun(self.g, other.g),
+            fun(self.b, other.b),
+            fun(self.a, other.a),
+        )
+    }
+}
+
+impl From<Color> for LinearColor {
+    fn from(c: Color) -> Self {
+        Self::new(
+            srgb_to_linear(c.red()),
+            srgb_to_linear(c.green()),
+            srgb_to_linear(c.blue()),
+            c.alpha(),
+        )
+    }
+}
+
+/// Encodes the color channels into sRGB, clamping out-of-range values.
+impl From<LinearColor> for Color {
+    fn from(c: LinearColor) -> Self {
+        Self::new(
+            linear_to_srgb(c.r.clamp(0.0, 1.0)),
+            linear_to_srgb(c.g.clamp(0.0, 1.0)),
+            linear_to_srgb(c.b.clamp(0.0, 1.0)),
+            c.a,
+        )
+    }
+}
+
+impl From<[f32; 4]> for LinearColor {
+    fn from(v: [f32; 4]) -> Self {
+        Self::new(v[0], v[1], v[2], v[3])
+    }
+}
+
+impl From<LinearColor> for [f32; 4] {
+    fn from(c: LinearColor) -> Self {
+        c.into_vec4()
+    }
+}
+
+impl From<LinearColor> for wgpu::Color {
+    fn from(c: LinearColor) -> Self {
+        Self {
+            r: c.r as f64,
+            g: c.g as f64,
+            b: c.b as f64,
+            a: c.a as f64,
+        }
+    }
+}
+
+/// Affects all the channels, including alpha.
+impl ops::Add for LinearColor {
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        self.zip(other, |a, b| a + b)
+    }
+}
+
+/// Affects all the channels, including alpha.
+impl ops::Sub for LinearColor {
+    type Output = Self;
+    fn sub(self, other: Self) -> Self {
+        self.zip(other, |a, b| a - b)
+    }
+}
+
+/// Affects all the channels, including alpha.
+impl ops::Mul for LinearColor {
+    type Output = Self;
+    fn mul(self, other: Self) -> Self {
+        self.zip(other, |a, b| a * b)
+    }
+}
+
+/// Affects all the channels, including alpha.
+impl ops::Mul<f32> for LinearColor {
+    type Output = Self;
+    fn mul(self, scale: f32) -> Self {
+        self.map(|v| v * scale)
+    }
+}
+
+impl ops::AddAssign for LinearColor {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl ops::MulAssign<f32> for LinearColor {
+    fn mul_assign(&mut self, scale: f32) {
+        *self = *self * scale;
+    }
+}
@@ -0,0 +1,862 @@
+mod gradient;
+mod linear;
+pub mod named;
+mod oklab;
+#[cfg(feature = "serde")]
+mod serialization;
+
+pub use gradient::Gradient;
+pub use linear::LinearColor;
+use std::{fmt, ops, str};
+
+/// Can be specified as 0xAARRGGBB, i.e. alpha occupies bits 24-31,
+/// red is in bits 16-23, green in 8-15, and blue in 0-7.
+///
+/// The color channels are assumed to be sRGB-encoded, while alpha is linear.
+///
+/// Casting to bytes via `bytemuck` exposes the native `u32` layout,
+/// which is B, G, R, A on little-endian machines.
+#[repr(transparent)]
+#[derive(Clone, Copy, Hash, PartialEq, PartialOrd)]
+pub struct Color(pub u32);
+
+unsafe impl bytemuck::Zeroable for Color {}
+unsafe impl bytemuck::Pod for Color {}
+
+const GAMMA: f32 = 2.2;
+
+/// Error produced when parsing a [`Color`] from a string.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ColorParseError {
+    /// The number of hex digits is not 3, 6, or 8.
+    InvalidLength(usize),
+    /// The string contains a character that is not a hex digit.
+    InvalidDigit(char),
+}
+
+impl fmt::Display for ColorParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Self::InvalidLength(len) => {
+                write!(f, "expected 3, 6, or 8 hex digits, got {}", len)
+            }
+            Self::InvalidDigit(c) => write!(f, "invalid hex digit {:?}", c),
+        }
+    }
+}
+
+impl std::error::Error for ColorParseError {}
+
+/// Per-channel blending function used by [`Color::blend`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum BlendMode {
+    /// Top color replaces the base.
+    Normal,
+    Multiply,
+    Screen,
+    Overlay,
+    /// Additive blending.
+    Add,
+    Subtract,
+    /// Minimum of the two, per channel.
+    Darken,
+    /// Maximum of the two, per channel.
+    Lighten,
+}
+
+impl BlendMode {
+    fn apply(self, base: f32, top: f32) -> f32 {
+        let value = match self {
+            Self::Normal => top,
+            Self::Multiply => base * top,
+            Self::Screen => base + top - base * top,
+            Self::Overlay => {
+                if base <= 0.5 {
+                    2.0 * base * top
+                } else {
+                    1.0 - 2.0 * (1.0 - base) * (1.0 - top)
+                }
+            }
+            Self::Add => base + top,
+            Self::Subtract => base - top,
+            Self::Darken => base.min(top),
+            Self::Lighten => base.max(top),
+        };
+        value.clamp(0.0, 1.0)
+    }
+}
+
+/// Decode an sRGB-encoded channel value into linear space.
+fn srgb_to_linear(value: f32) -> f32 {
+    if value <= 0.04045 {
+        value / 12.92
+    } else {
+        ((value + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Encode a linear channel value with the sRGB transfer function.
+fn linear_to_srgb(value: f32) -> f32 {
+    if value <= 0.0031308 {
+        value * 12.92
+    } else {
+        1.055 * value.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+/// Convert a hue (in degrees) and chroma into RGB, adding `offset` to all channels.
+fn hue_chroma_to_rgb(hue: f32, chroma: f32, offset: f32) -> [f32; 3] {
+    let sector = hue.rem_euclid(360.0) / 60.0;
+    let x = chroma * (1.0 - (sector % 2.0 - 1.0).abs());
+    let (r, g, b) = match sector as u32 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+    [r + offset, g + offset, b + offset]
+}
+
+impl Color {
+    pub const BLACK_TRANSPARENT: Self = Self(0x0);
+    pub const BLACK_OPAQUE: Self = Self(0xFF000000);
+    pub const RED: Self = Self(0xFFFF0000);
+    /// Full-intensity green, which is called "lime" in CSS.
+    pub const GREEN: Self = Self(0xFF00FF00);
+    pub const BLUE: Self = Self(0xFF0000FF);
+    pub const WHITE: Self = Self(0xFFFFFFFF);
+    pub const YELLOW: Self = Self(0xFFFFFF00);
+    pub const CYAN: Self = Self(0xFF00FFFF);
+    pub const MAGENTA: Self = Self(0xFFFF00FF);
+
+    fn import(value: f32) -> u32 {
+        (value.clamp(0.0, 1.0) * 255.0).round() as u32
+    }
+
+    pub fn new(red: f32, green: f32, blue: f32, alpha: f32) -> Self {
+        Self(
+            (Self::import(alpha) << 24)
+                | (Self::import(red) << 16)
+                | (Self::import(green) << 8)
+                | Self::import(blue),
+        )
+    }
+
+    /// Create from 8-bit channels, usable in constant expressions.
+    pub const fn new_u8(red: u8, green: u8, blue: u8, alpha: u8) -> Self {
+        Self((alpha as u32) << 24 | (red as u32) << 16 | (green as u32) << 8 | blue as u32)
+    }
+
+    /// Create from a `0xRRGGBBAA` value.
+    pub const fn from_rgba_u32(value: u32) -> Self {
+        Self(value.rotate_right(8))
+    }
+    /// Create from a `0xAARRGGBB` value, which is the internal layout.
+    pub const fn from_argb_u32(value: u32) -> Self {
+        Self(value)
+    }
+    /// Create from a `0xAABBGGRR` value.
+    pub const fn from_abgr_u32(value: u32) -> Self {
+        Self(value & 0xFF00FF00 | (value & 0xFF) << 16 | (value >> 16) & 0xFF)
+    }
+    /// Return a `0xRRGGBBAA` value.
+    pub const fn to_rgba_u32(self) -> u32 {
+        self.0.rotate_left(8)
+    }
+    /// Return a `0xAARRGGBB` value, which is the internal layout.
+    pub const fn to_argb_u32(self) -> u32 {
+        self.0
+    }
+    /// Return a `0xAABBGGRR` value.
+    pub const fn to_abgr_u32(self) -> u32 {
+        Self::from_abgr_u32(self.0).0
+    }
+
+    pub fn from_rgba(d: [f32; 4]) -> Self {
+        Self::new(d[0], d[1], d[2], d[3])
+    }
+    pub fn from_rgb_alpha(d: [f32; 3], alpha: f32) -> Self {
+        Self::new(d[0], d[1], d[2], alpha)
+    }
+
+    /// Parse a CSS-style hex string: `#RGB`, `#RRGGBB`, or `#RRGGBBAA`.
+    ///
+    /// The leading `#` is optional. Alpha is opaque when not specified.
+    pub fn from_hex(s: &str) -> Result<Self, ColorParseError> {
+        let digits = s.strip_prefix('#').unwrap_or(s);
+        let mut value = 0u32;
+        for c in digits.chars() {
+            let d = c.to_digit(16).ok_or(ColorParseError::InvalidDigit(c))?;
+            value = (value << 4) | d;
+        }
+        let rgba = match digits.len() {
+            3 => {
+                // expand each nibble into a full byte
+                let (r, g, b) = ((value >> 8) & 0xF, (value >> 4) & 0xF, value & 0xF);
+                (r * 0x11) << 24 | (g * 0x11) << 16 | (b * 0x11) << 8 | 0xFF
+            }
+            6 => value << 8 | 0xFF,
+            8 => value,
+            other => return Err(ColorParseError::InvalidLength(other)),
+        };
+        Ok(Self::from_rgba_u32(rgba))
+    }
+
+    /// Look up one of the CSS named colors, like "cornflowerblue".
+    ///
+    /// The name is case-insensitive. See [`named`] for the constants.
+    pub fn from_name(name: &str) -> Option<Self> {
+        named::find(name)
+    }
+
+    /// Format as a `#RRGGBBAA` string, the inverse of [`Color::from_hex`].
+    pub fn to_hex_string(self) -> String {
+        self.to_hex(true)
+    }
+
+    /// Format as `#RRGGBBAA`, or as `#RRGGBB` if `with_alpha` is false.
+    pub fn to_hex(self, with_alpha: bool) -> String {
+        if with_alpha {
+            format!("#{:x}", self)
+        } else {
+            format!("#{:06x}", self.to_rgba_u32() >> 8)
+        }
+    }
+
+    /// Create an opaque color from an arbitrary seed, such as an entity ID.
+    ///
+    /// Consecutive seeds advance the hue by the golden ratio, which keeps the
+    /// colors distinguishable. The mapping is stable across crate versions.
+    pub fn from_hash(seed: u64) -> Self {
+        // 2^64 divided by the golden ratio
+        let fraction = seed.wrapping_mul(0x9E37_79B9_7F4A_7C15) >> 32;
+        let hue = fraction as f32 / (1u64 << 32) as f32 * 360.0;
+        Self::from_hsl(hue, 0.65, 0.55, 1.0)
+    }
+
+    /// Create an opaque color with a random hue, see [`Color::from_hash`].
+    #[cfg(feature = "rand")]
+    pub fn random(rng: &mut impl rand::Rng) -> Self {
+        Self::from_hash(rng.gen())
+    }
+
+    /// Approximate the color of a black body at the given temperature in Kelvin.
+    ///
+    /// Uses the curve fit by Tanner Helland. The temperature is clamped
+    /// to `[1000, 40000]`, and the result is opaque.
+    pub fn from_kelvin(temperature: f32) -> Self {
+        let t = temperature.clamp(1000.0, 40000.0) / 100.0;
+        let red = if t <= 66.0 {
+            255.0
+        } else {
+            329.69873 * (t - 60.0).powf(-0.13320476)
+        };
+        let green = if t <= 66.0 {
+            99.4708 * t.ln() - 161.11957
+        } else {
+            288.12217 * (t - 60.0).powf(-0.075514849)
+        };
+        let blue = if t >= 66.0 {
+            255.0
+        } else if t <= 19.0 {
+            0.0
+        } else {
+            138.51773 * (t - 10.0).ln() - 305.0448
+        };
+        Self::new(red / 255.0, green / 255.0, blue / 255.0, 1.0)
+    }
+
+    /// Create from hue (in degrees, wrapping), saturation, lightness, and alpha.
+    pub fn from_hsl(hue: f32, saturation: f32, lightness: f32, alpha: f32) -> Self {
+        let s = saturation.clamp(0.0, 1.0);
+        let l = lightness.clamp(0.0, 1.0);
+        let chroma = (1.0 - (2.0 * l - 1.0).abs()) * s;
+        Self::from_rgb_alpha(hue_chroma_to_rgb(hue, chroma, l - 0.5 * chroma), alpha)
+    }
+
+    /// Return hue (in degrees), saturation, and lightness of the HSL model.
+    pub fn to_hsl(self) -> (f32, f32, f32) {
+        (self.hue(), self.saturation(), self.lightness())
+    }
+
+    /// Same as [`Color::to_hsl`] with alpha appended.
+    pub fn to_hsla(self) -> (f32, f32, f32, f32) {
+        let (h, s, l) = self.to_hsl();
+        (h, s, l, self.alpha())
+    }
+
+    /// Create from hue (in degrees, wrapping), saturation, value, and alpha.
+    pub fn from_hsv(hue: f32, saturation: f32, value: f32, alpha: f32) -> Self {
+        let v = value.clamp(0.0, 1.0);
+        let chroma = v * saturation.clamp(0.0, 1.0);
+        Self::from_rgb_alpha(hue_chroma_to_rgb(hue, chroma, v - chroma), alpha)
+    }
+
+    /// Return hue (in degrees), saturation, and value of the HSV model.
+    pub fn to_hsv(self) -> (f32, f32, f32) {
+        let (min, max) = self.min_max();
+        let saturation = if max == 0.0 { 0.0 } else { (max - min) / max };
+        (self.hue(), saturation, max)
+    }
+
+    /// Same as [`Color::to_hsv`] with alpha appended.
+    pub fn to_hsva(self) -> (f32, f32, f32, f32) {
+        let (h, s, v) = self.to_hsv();
+        (h, s, v, self.alpha())
+    }
+
+    fn min_max(self) -> (f32, f32) {
+        let (r, g, b) = (self.red(), self.green(), self.blue());
+        (r.min(g).min(b), r.max(g).max(b))
+    }
+
+    /// Hue in degrees, in range `[0, 360)`. Zero for achromatic colors.
+    pub fn hue(self) -> f32 {
+        let (r, g, b) = (self.red(), self.green(), self.blue());
+        let (min, max) = self.min_max();
+        let delta = max - min;
+        if delta == 0.0 {
+            return 0.0;
+        }
+        let sector = if max == r {
+            ((g - b) / delta).rem_euclid(6.0)
+        } else if max == g {
+            (b - r) / delta + 2.0
+        } else {
+            (r - g) / delta + 4.0
+        };
+        60.0 * sector
+    }
+
+    /// Saturation as defined by the HSL model.
+    pub fn saturation(self) -> f32 {
+        let (min, max) = self.min_max();
+        let delta = max - min;
+        if delta == 0.0 {
+            0.0
+        } else {
+            delta / (1.0 - (max + min - 1.0).abs())
+        }
+    }
+
+    /// Lightness as defined by the HSL model.
+    pub fn lightness(self) -> f32 {
+        let (min, max) = self.min_max();
+        0.5 * (max + min)
+    }
+
+    fn map_hsl(self, fun: impl FnOnce(f32, f32, f32) -> (f32, f32, f32)) -> Self {
+        let (h, s, l, a) = self.to_hsla();
+        let (h, s, l) = fun(h, s, l);
+        Self::from_hsl(h, s, l, a)
+    }
+
+    /// Increase the HSL lightness by `amount`, which is clamped to `[0, 1]`.
+    pub fn lighten(self, amount: f32) -> Self {
+        let amount = amount.clamp(0.0, 1.0);
+        self.map_hsl(|h, s, l| (h, s, l + amount))
+    }
+
+    /// Decrease the HSL lightness by `amount`, which is clamped to `[0, 1]`.
+    pub fn darken(self, amount: f32) -> Self {
+        let amount = amount.clamp(0.0, 1.0);
+        self.map_hsl(|h, s, l| (h, s, l - amount))
+    }
+
+    /// Increase the HSL saturation by `amount`, which is clamped to `[0, 1]`.
+    pub fn saturate(self, amount: f32) -> Self {
+        let amount = amount.clamp(0.0, 1.0);
+        self.map_hsl(|h, s, l| (h, s + amount, l))
+    }
+
+    /// Decrease the HSL saturation by `amount`, which is clamped to `[0, 1]`.
+    pub fn desaturate(self, amount: f32) -> Self {
+        let amount = amount.clamp(0.0, 1.0);
+        self.map_hsl(|h, s, l| (h, s - amount, l))
+    }
+
+    /// Rotate the hue by the given angle in degrees.
+    pub fn rotate_hue(self, degrees: f32) -> Self {
+        self.map_hsl(|h, s, l| (h + degrees, s, l))
+    }
+
+    /// Interpolate towards `other` by `t`, which is clamped to `[0, 1]`.
+    ///
+    /// Color channels are mixed in linear space, alpha is mixed as is.
+    pub fn lerp(self, other: Self, t: f32) -> Self {
+        let t = t.clamp(0.0, 1.0);
+        let mix = |a: f32, b: f32| a + (b - a) * t;
+        let mix_linear = |a: f32, b: f32| linear_to_srgb(mix(srgb_to_linear(a), srgb_to_linear(b)));
+        Self::new(
+            mix_linear(self.red(), other.red()),
+            mix_linear(self.green(), other.green()),
+            mix_linear(self.blue(), other.blue()),
+            mix(self.alpha(), other.alpha()),
+        )
+    }
+
+    /// Interpolate towards `other` by `t`, which is clamped to `[0, 1]`.
+    ///
+    /// All channels are mixed as they are stored, which is cheaper than
+    /// [`Color::lerp`] but produces darker midpoints.
+    pub fn lerp_srgb(self, other: Self, t: f32) -> Self {
+        let t = t.clamp(0.0, 1.0);
+        let a = self.into_vec4();
+        let b = other.into_vec4();
+        let mut mixed = [0.0; 4];
+        for (m, (a, b)) in mixed.iter_mut().zip(a.iter().zip(b.iter())) {
+            *m = a + (b - a) * t;
+        }
+        Self::from_rgba(mixed)
+    }
+
+    /// Relative luminance of the color in linear space, using Rec. 709 weights.
+    pub fn luminance(self) -> f32 {
+        let [r, g, b, _] = self.into_vec4_linear();
+        0.2126 * r + 0.7152 * g + 0.0722 * b
+    }
+
+    /// Return a gray of the same luminance, preserving alpha.
+    pub fn to_grayscale(self) -> Self {
+        let value = linear_to_srgb(self.luminance());
+        Self::new(value, value, value, self.alpha())
+    }
+
+    /// Move towards the gray of [`Color::to_grayscale`] by `t`, which is clamped to `[0, 1]`.
+    pub fn grayscale_amount(self, t: f32) -> Self {
+        self.lerp(self.to_grayscale(), t)
+    }
+
+    /// Invert the color channels, preserving alpha.
+    pub const fn invert(self) -> Self {
+        Self(self.0 ^ 0x00FF_FFFF)
+    }
+
+    /// WCAG contrast ratio between the two colors, in range `[1, 21]`.
+    pub fn contrast_ratio(self, other: Self) -> f32 {
+        let a = self.luminance();
+        let b = other.luminance();
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
+
+    /// Euclidean distance between the stored RGB channels, in range `[0, sqrt(3)]`.
+    ///
+    /// Cheap but not perceptually uniform, unlike [`Color::distance`]. Alpha is ignored.
+    pub fn distance_rgb(self, other: Self) -> f32 {
+        let sq = |a: f32, b: f32| (a - b) * (a - b);
+        (sq(self.red(), other.red())
+            + sq(self.green(), other.green())
+            + sq(self.blue(), other.blue()))
+        .sqrt()
+    }
+
+    /// Pick black or white, whichever has the higher contrast on `background`.
+    pub fn readable_on(background: Self) -> Self {
+        let black = Self::BLACK_OPAQUE.contrast_ratio(background);
+        let white = Self::WHITE.contrast_ratio(background);
+        if black >= white {
+            Self::BLACK_OPAQUE
+        } else {
+            Self::WHITE
+        }
+    }
+
+    /// Composite this color over the `background` with the source-over operator.
+    pub fn over(self, background: Self) -> Self {
+        let src = self.into_vec4();
+        let dst = background.into_vec4();
+        let alpha = src[3] + dst[3] * (1.0 - src[3]);
+        if alpha <= 0.0 {
+            return Self::BLACK_TRANSPARENT;
+        }
+        let mix = |s: f32, d: f32| (s * src[3] + d * dst[3] * (1.0 - src[3])) / alpha;
+        Self::new(
+            mix(src[0], dst[0]),
+            mix(src[1], dst[1]),
+            mix(src[2], dst[2]),
+            alpha,
+        )
+    }
+
+    /// Blend `top` onto this color with the given mode.
+    ///
+    /// The color channels are blended in linear space and then mixed in by
+    /// the alpha of `top`. Alpha is composited the same way as [`Color::over`].
+    pub fn blend(self, top: Self, mode: BlendMode) -> Self {
+        let top_alpha = top.alpha();
+        let mix = |base: f32, top: f32| {
+            let base = srgb_to_linear(base);
+            let blended = mode.apply(base, srgb_to_linear(top));
+            linear_to_srgb(base + (blended - base) * top_alpha)
+        };
+        Self::new(
+            mix(self.red(), top.red()),
+            mix(self.green(), top.green()),
+            mix(self.blue(), top.blue()),
+            top_alpha + self.alpha() * (1.0 - top_alpha),
+        )
+    }
+
+    /// Multiply the color channels by alpha.
+    pub fn premultiply(self) -> Self {
+        self * self.alpha()
+    }
+
+    /// Divide the color channels by alpha, the inverse of [`Color::premultiply`].
+    ///
+    /// Returns transparent black when alpha is zero.
+    pub fn unpremultiply(self) -> Self {
+        let alpha = self.alpha();
+        if alpha == 0.0 {
+            Self::BLACK_TRANSPARENT
+        } else {
+            self * alpha.recip()
+        }
+    }
+
+    /// Decode the color channels from sRGB into linear space.
+    ///
+    /// Note that 8 bits per channel are not enough to store linear values
+    /// without banding in the dark range.
+    pub fn to_linear(self) -> Self {
+        Self::new(
+            srgb_to_linear(self.red()),
+            srgb_to_linear(self.green()),
+            srgb_to_linear(self.blue()),
+            self.alpha(),
+        )
+    }
+
+    /// Encode the color channels from linear space into sRGB.
+    /// This is the inverse of [`Color::to_linear`].
+    pub fn from_linear(self) -> Self {
+        Self::new(
+            linear_to_srgb(self.red()),
+            linear_to_srgb(self.green()),
+            linear_to_srgb(self.blue()),
+            self.alpha(),
+        )
+    }
+
+    /// Create from linear RGBA values, encoding the color channels into sRGB.
+    pub fn from_vec4_linear(d: [f32; 4]) -> Self {
+        Self::new(
+            linear_to_srgb(d[0]),
+            linear_to_srgb(d[1]),
+            linear_to_srgb(d[2]),
+            d[3],
+        )
+    }
+
+    fn export(self, index: u32) -> f32 {
+        ((self.0 >> (index << 3)) & 0xFF) as f32 / 255.0
+    }
+    pub fn red(self) -> f32 {
+        self.export(2)
+    }
+    pub fn green(self) -> f32 {
+        self.export(1)
+    }
+    pub fn blue(self) -> f32 {
+        self.export(0)
+    }
+    pub fn alpha(self) -> f32 {
+        self.export(3)
+    }
+
+    fn with_channel(self, index: u32, value: f32) -> Self {
+        let shift = index << 3;
+        Self(self.0 & !(0xFF << shift) | Self::import(value) << shift)
+    }
+    pub fn with_red(self, red: f32) -> Self {
+        self.with_channel(2, red)
+    }
+    pub fn with_green(self, green: f32) -> Self {
+        self.with_channel(1, green)
+    }
+    pub fn with_blue(self, blue: f32) -> Self {
+        self.with_channel(0, blue)
+    }
+    pub fn with_alpha(self, alpha: f32) -> Self {
+        self.with_channel(3, alpha)
+    }
+
+    pub fn into_vec4(self) -> [f32; 4] {
+        [self.red(), self.green(), self.blue(), self.alpha()]
+    }
+    /// Return RGBA with the color channels decoded into linear space.
+    pub fn into_vec4_linear(self) -> [f32; 4] {
+        [
+            srgb_to_linear(self.red()),
+            srgb_to_linear(self.green()),
+            srgb_to_linear(self.blue()),
+            self.alpha(),
+        ]
+    }
+    /// Return the bytes in R, G, B, A order.
+    pub fn to_bytes(self) -> [u8; 4] {
+        self.to_rgba_bytes()
+    }
+    /// Return the bytes in R, G, B, A order, matching `Rgba8Unorm` textures.
+    pub fn to_rgba_bytes(self) -> [u8; 4] {
+        let [b, g, r, a] = self.0.to_le_bytes();
+        [r, g, b, a]
+    }
+    /// Return the bytes in B, G, R, A order, matching `Bgra8Unorm` textures.
+    ///
+    /// The preferred surface format is BGRA on some platforms.
+    pub fn to_bgra_bytes(self) -> [u8; 4] {
+        self.0.to_le_bytes()
+    }
+
+    /// Return the bytes of a texel in the given texture format.
+    ///
+    /// The stored channels are written as they are, so `*Srgb` formats decode
+    /// them into linear space on sampling, while linear formats don't.
+    ///
+    /// Panics on formats other than RGBA8 and BGRA8.
+    pub fn pack(self, format: wgpu::TextureFormat) -> [u8; 4] {
+        match format {
+            wgpu::TextureFormat::Rgba8Unorm | wgpu::TextureFormat::Rgba8UnormSrgb => {
+                self.to_rgba_bytes()
+            }
+            wgpu::TextureFormat::Bgra8Unorm | wgpu::TextureFormat::Bgra8UnormSrgb => {
+                self.to_bgra_bytes()
+            }
+            other => panic!("Unable to pack a color into {:?}", other),
+        }
+    }
+
+    /// Create from the bytes of a texel in the given format, the inverse of [`Color::pack`].
+    pub fn unpack(bytes: [u8; 4], format: wgpu::TextureFormat) -> Self {
+        match format {
+            wgpu::TextureFormat::Rgba8Unorm | wgpu::TextureFormat::Rgba8UnormSrgb => {
+                Self::from(bytes)
+            }
+            wgpu::TextureFormat::Bgra8Unorm | wgpu::TextureFormat::Bgra8UnormSrgb => {
+                Self(u32::from_le_bytes(bytes))
+            }
+            other => panic!("Unable to unpack a color from {:?}", other),
+        }
+    }
+    pub fn into_vec4_gamma(self) -> [f32; 4] {
+        [
+            self.red().powf(GAMMA),
+            self.green().powf(GAMMA),
+            self.blue().powf(GAMMA),
+            self.alpha().powf(GAMMA),
+        ]
+    }
+
+    /// Convert into a `wgpu::Color` with the color channels multiplied by alpha,
+    /// suitable for clearing targets that are composited as premultiplied.
+    pub fn to_wgpu_premultiplied(self) -> wgpu::Color {
+        let a = self.alpha() as f64;
+        wgpu::Color {
+            r: self.red() as f64 * a,
+            g: self.green() as f64 * a,
+            b: self.blue() as f64 * a,
+            a,
+        }
+    }
+
+    /// Convert into a `wgpu::Color` in linear space, suitable for clearing
+    /// targets with `*Srgb` formats.
+    pub fn to_wgpu_linear(self) -> wgpu::Color {
+        let [r, g, b, a] = self.into_vec4_linear();
+        wgpu::Color {
+            r: r as f64,
+            g: g as f64,
+            b: b as f64,
+            a: a as f64,
+        }
+    }
+}
+
+impl Color {
+    fn combine(self, other: Self, fun: impl Fn(f32, f32) -> f32) -> Self {
+        let a = self.into_vec4();
+        let b = other.into_vec4();
+        Self::new(
+            fun(a[0], b[0]),
+            fun(a[1], b[1]),
+            fun(a[2], b[2]),
+            fun(a[3], b[3]),
+        )
+    }
+}
+
+/// Modulates all channels, including alpha.
+impl ops::Mul for Color {
+    type Output = Self;
+    fn mul(self, other: Self) -> Self {
+        self.combine(other, |a, b| a * b)
+    }
+}
+
+/// Scales the brightness, alpha is left untouched.
+impl ops::Mul<f32> for Color {
+    type Output = Self;
+    fn mul(self, scale: f32) -> Self {
+        Self::new(
+            self.red() * scale,
+            self.green() * scale,
+            self.blue() * scale,
+            self.alpha(),
+        )
+    }
+}
+
+/// Adds all channels, including alpha, saturating at 1.
+impl ops::Add for Color {
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        self.combine(other, |a, b| a + b)
+    }
+}
+
+/// Subtracts all channels, including alpha, saturating at 0.
+impl ops::Sub for Color {
+    type Output = Self;
+    fn sub(self, other: Self) -> Self {
+        self.combine(other, |a, b| a - b)
+    }
+}
+
+impl ops::MulAssign for Color {
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+impl ops::MulAssign<f32> for Color {
+    fn mul_assign(&mut self, scale: f32) {
+        *self = *self * scale;
+    }
+}
+
+impl ops::AddAssign for Color {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl ops::SubAssign for Color {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+/// Interprets the array as R, G, B, A.
+impl From<[f32; 4]> for Color {
+    fn from(d: [f32; 4]) -> Self {
+        Self::from_rgba(d)
+    }
+}
+
+/// Interprets the tuple as R, G, B, A.
+impl From<(f32, f32, f32, f32)> for Color {
+    fn from((r, g, b, a): (f32, f32, f32, f32)) -> Self {
+        Self::new(r, g, b, a)
+    }
+}
+
+/// Interprets the bytes as R, G, B, A.
+impl From<[u8; 4]> for Color {
+    fn from([r, g, b, a]: [u8; 4]) -> Self {
+        Self::new_u8(r, g, b, a)
+    }
+}
+
+/// Interprets the bytes as R, G, B, A.
+impl From<(u8, u8, u8, u8)> for Color {
+    fn from((r, g, b, a): (u8, u8, u8, u8)) -> Self {
+        Self::new_u8(r, g, b, a)
+    }
+}
+
+/// Produces R, G, B, A.
+impl From<Color> for [f32; 4] {
+    fn from(c: Color) -> Self {
+        c.into_vec4()
+    }
+}
+
+/// Produces R, G, B, A.
+impl From<Color> for (f32, f32, f32, f32) {
+    fn from(c: Color) -> Self {
+        (c.red(), c.green(), c.blue(), c.alpha())
+    }
+}
+
+/// Produces R, G, B, A.
+impl From<Color> for [u8; 4] {
+    fn from(c: Color) -> Self {
+        c.to_bytes()
+    }
+}
+
+/// Produces R, G, B, A.
+impl From<Color> for (u8, u8, u8, u8) {
+    fn from(c: Color) -> Self {
+        let [r, g, b, a] = c.to_bytes();
+        (r, g, b, a)
+    }
+}
+
+/// Passes the channels as is, which is suitable for targets with linear formats.
+/// Targets with `*Srgb` formats treat `wgpu::Color` as linear,
+/// see [`Color::to_wgpu_linear`] for them.
+impl From<Color> for wgpu::Color {
+    fn from(c: Color) -> Self {
+        Self {
+            r: c.red() as f64,
+            g: c.green() as f64,
+            b: c.blue() as f64,
+            a: c.alpha() as f64,
+        }
+    }
+}
+
+/// Formats as `Color(#rrggbbaa)`.
+impl fmt::Debug for Color {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Color(#{:x})", self)
+    }
+}
+
+/// Formats as `#rrggbbaa`.
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "#{:x}", self)
+    }
+}
+
+/// Formats as `rrggbbaa`, i.e. with the alpha byte last.
+impl fmt::LowerHex for Color {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:08x}", self.to_rgba_u32())
+    }
+}
+
+/// Formats as `RRGGBBAA`, i.e. with the alpha byte last.
+impl fmt::UpperHex for Color {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:08X}", self.to_rgba_u32())
+    }
+}
+
+impl str::FromStr for Color {
+    type Err = ColorParseError;
+    fn from_str(s: &str) -> Result<Self, ColorParseError> {
+        Self::from_hex(s)
+    }
+}
+
+impl Default for Color {
+    fn default() -> Self {
+        Color::BLACK_OPAQUE
+    }
+}
@@ -0,0 +1,83 @@
+use super::{linear_to_srgb, Color};
+
+fn cbrt_lms(r: f32, g: f32, b: f32) -> [f32; 3] {
+    [
+        (0.41222147 * r + 0.53633254 * g + 0.05144599 * b).cbrt(),
+        (0.2119035 * r + 0.6806996 * g + 0.10739696 * b).cbrt(),
+        (0.08830246 * r + 0.28171884 * g + 0.6299787 * b).cbrt(),
+    ]
+}
+
+/// Conversions to and from the perceptual OKLab space.
+///
+/// OKLab coordinates are `[L, a, b]`, where `L` is in `[0, 1]`,
+/// and OKLCH coordinates are `[L, C, h]` with hue `h` in degrees.
+impl Color {
+    /// Return the OKLab coordinates of this color.
+    pub fn to_oklab(self) -> [f32; 3] {
+        let [r, g, b, _] = self.into_vec4_linear();
+        let [l, m, s] = cbrt_lms(r, g, b);
+        [
+            0.21045426 * l + 0.7936178 * m - 0.00407205 * s,
+            1.9779985 * l - 2.4285922 * m + 0.4505937 * s,
+            0.02590404 * l + 0.78277177 * m - 0.80867577 * s,
+        ]
+    }
+
+    /// Create from OKLab coordinates and alpha.
+    ///
+    /// Colors outside of the sRGB gamut are clamped per channel.
+    pub fn from_oklab(l: f32, a: f32, b: f32, alpha: f32) -> Self {
+        let l_ = l + 0.39633778 * a + 0.21580376 * b;
+        let m_ = l - 0.10556135 * a - 0.06385417 * b;
+        let s_ = l - 0.08948418 * a - 1.2914855 * b;
+        let (l3, m3, s3) = (l_ * l_ * l_, m_ * m_ * m_, s_ * s_ * s_);
+        let encode = |v: f32| linear_to_srgb(v.clamp(0.0, 1.0));
+        Self::new(
+            encode(4.0767417 * l3 - 3.3077116 * m3 + 0.23096993 * s3),
+            encode(-1.268438 * l3 + 2.6097574 * m3 - 0.34131938 * s3),
+            encode(-0.0041960863 * l3 - 0.7034186 * m3 + 1.7076147 * s3),
+            alpha,
+        )
+    }
+
+    /// Return the OKLCH coordinates of this color, with hue in `[0, 360)`.
+    pub fn to_oklch(self) -> [f32; 3] {
+        let [l, a, b] = self.to_oklab();
+        let hue = b.atan2(a).to_degrees();
+        [l, a.hypot(b), if hue < 0.0 { hue + 360.0 } else { hue }]
+    }
+
+    /// Create from OKLCH coordinates (hue in degrees) and alpha.
+    pub fn from_oklch(l: f32, chroma: f32, hue: f32, alpha: f32) -> Self {
+        let (sin, cos) = hue.to_radians().sin_cos();
+        Self::from_oklab(l, chroma * cos, chroma * sin, alpha)
+    }
+
+    /// Perceptual difference to `other`, as the Euclidean distance in OKLab scaled by 100.
+    ///
+    /// Values below ~2 are imperceptible, and black to white is 100.
+    /// Alpha is ignored, see [`Color::distance_rgb`] for a cheaper metric.
+    pub fn distance(self, other: Self) -> f32 {
+        let a = self.to_oklab();
+        let b = other.to_oklab();
+        let sq = |i: usize| (a[i] - b[i]) * (a[i] - b[i]);
+        100.0 * (sq(0) + sq(1) + sq(2)).sqrt()
+    }
+
+    /// Interpolate towards `other` by `t` in OKLab space, which is clamped to `[0, 1]`.
+    ///
+    /// Produces perceptually even gradients. Alpha is mixed linearly.
+    pub fn lerp_oklab(self, other: Self, t: f32) -> Self {
+        let t = t.clamp(0.0, 1.0);
+        let mix = |a: f32, b: f32| a + (b - a) * t;
+        let a = self.to_oklab();
+        let b = other.to_oklab();
+        Self::from_oklab(
+            mix(a[0], b[0]),
+            mix(a[1], b[1]),
+            mix(a[2], b[2]),
+            mix(self.alpha(), other.alpha()),
+        )
+    }
+}
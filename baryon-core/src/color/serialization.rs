@@ -0,0 +1,58 @@
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+
+/// Serialized as a `#RRGGBBAA` string in human-readable formats,
+/// and as the raw `u32` otherwise.
+///
+/// Human-readable formats can also provide `[r, g, b, a]` floats.
+impl Serialize for super::Color {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&self.to_hex_string())
+        } else {
+            serializer.serialize_u32(self.0)
+        }
+    }
+}
+
+struct ColorVisitor;
+
+impl<'de> de::Visitor<'de> for ColorVisitor {
+    type Value = super::Color;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a \"#RRGGBBAA\" color string or [r, g, b, a] floats")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        super::Color::from_hex(value)
+            .map_err(|e| E::custom(format_args!("invalid color {:?}: {}", value, e)))
+    }
+
+    fn visit_u32<E: de::Error>(self, value: u32) -> Result<Self::Value, E> {
+        Ok(super::Color(value))
+    }
+
+    fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut rgba = [0.0f32; 4];
+        for (i, channel) in rgba.iter_mut().enumerate() {
+            *channel = seq
+                .next_element()?
+                .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+        }
+        if seq.next_element::<de::IgnoredAny>()?.is_some() {
+            return Err(de::Error::invalid_length(5, &self));
+        }
+        Ok(super::Color::from_rgba(rgba))
+    }
+}
+
+impl<'de> Deserialize<'de> for super::Color {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(ColorVisitor)
+        } else {
+            deserializer.deserialize_u32(ColorVisitor)
+        }
+    }
+}
@@ -0,0 +1,277 @@
+use super::ColorSpace;
+use std::{fs::File, io, path::Path};
+use wgpu::util::DeviceExt as _;
+
+impl super::Context {
+    /// Load an image file holding colors, see [`Context::load_image_with`].
+    pub fn load_image(&mut self, path_ref: impl AsRef<Path>) -> super::ImageRef {
+        self.load_image_with(path_ref, ColorSpace::Srgb)
+    }
+
+    /// Decode an image in any format known to the `image` crate from memory.
+    ///
+    /// Fails if the bytes aren't an image in a supported format.
+    pub fn load_image_from_bytes(
+        &mut self,
+        bytes: &[u8],
+        space: ColorSpace,
+    ) -> Result<super::ImageRef, image::ImageError> {
+        let img = image::load_from_memory(bytes)?.to_rgba8();
+        let (texture, size) = self.upload_rgba(&img, None, space);
+        Ok(self.add_image(texture, size, Some(rgba_format(space))))
+    }
+
+    /// Load an image file, with the format picked by the extension.
+    pub fn load_image_with(
+        &mut self,
+        path_ref: impl AsRef<Path>,
+        space: ColorSpace,
+    ) -> super::ImageRef {
+        let path = path_ref.as_ref();
+        let image_format = image::ImageFormat::from_extension(path.extension().unwrap())
+            .unwrap_or_else(|| panic!("Unrecognized image extension: {:?}", path.extension()));
+
+        let label = path.display().to_string();
+        let file = File::open(path)
+            .unwrap_or_else(|e| panic!("Unable to open {}: {:?}", path.display(), e));
+        let mut buf_reader = io::BufReader::new(file);
+
+        let (texture, size, format) = if image_format == image::ImageFormat::Dds {
+            let dds = ddsfile::Dds::read(&mut buf_reader)
+                .unwrap_or_else(|e| panic!("Unable to read {}: {:?}", path.display(), e));
+
+            println!("Header {:?}", dds.header);
+            let mip_level_count = dds.get_num_mipmap_levels();
+            let (dimension, depth_or_array_layers) = match dds.header10 {
+                Some(ref h) => match h.resource_dimension {
+                    ddsfile::D3D10ResourceDimension::Texture2D => {
+                        (wgpu::TextureDimension::D2, h.array_size)
+                    }
+                    ddsfile::D3D10ResourceDimension::Texture3D => {
+                        (wgpu::TextureDimension::D3, dds.get_depth())
+                    }
+                    other => panic!("Unsupported resource dimension {:?}", other),
+                },
+                None => match dds.header.depth {
+                    None | Some(1) => (wgpu::TextureDimension::D2, 1),
+                    Some(other) => (wgpu::TextureDimension::D3, other),
+                },
+            };
+
+            let format = if let Some(fourcc) = dds.header.spf.fourcc {
+                match (fourcc.0, space) {
+                    (ddsfile::FourCC::BC1_UNORM, ColorSpace::Srgb) => {
+                        wgpu::TextureFormat::Bc1RgbaUnormSrgb
+                    }
+                    (ddsfile::FourCC::BC1_UNORM, ColorSpace::Linear) => {
+                        wgpu::TextureFormat::Bc1RgbaUnorm
+                    }
+                    (ddsfile::FourCC::BC2_UNORM, ColorSpace::Srgb) => {
+                        wgpu::TextureFormat::Bc2RgbaUnormSrgb
+                    }
+                    (ddsfile::FourCC::BC2_UNORM, ColorSpace::Linear) => {
+                        wgpu::TextureFormat::Bc2RgbaUnorm
+                    }
+                    (ddsfile::FourCC::BC3_UNORM, ColorSpace::Srgb) => {
+                        wgpu::TextureFormat::Bc3RgbaUnormSrgb
+                    }
+                    (ddsfile::FourCC::BC3_UNORM, ColorSpace::Linear) => {
+                        wgpu::TextureFormat::Bc3RgbaUnorm
+                    }
+                    (ddsfile::FourCC::BC4_UNORM, _) => wgpu::TextureFormat::Bc4RUnorm,
+                    (ddsfile::FourCC::BC4_SNORM, _) => wgpu::TextureFormat::Bc4RSnorm,
+                    (ddsfile::FourCC::BC5_UNORM, _) => wgpu::TextureFormat::Bc5RgUnorm,
+                    (ddsfile::FourCC::BC5_SNORM, _) => wgpu::TextureFormat::Bc5RgSnorm,
+                    (ref other, _) => panic!("Unsupported DDS FourCC {:?}", other),
+                }
+            } else {
+                assert_eq!(dds.header.spf.rgb_bit_count, Some(32));
+                rgba_format(space)
+            };
+
+            let desc = wgpu::TextureDescriptor {
+                label: Some(&label),
+                size: wgpu::Extent3d {
+                    width: dds.header.width,
+                    height: dds.header.height,
+                    depth_or_array_layers,
+                },
+                mip_level_count,
+                sample_count: 1,
+                dimension,
+                format,
+                usage: wgpu::TextureUsages::TEXTURE_BINDING,
+            };
+            let texture = self
+                .device
+                .create_texture_with_data(&self.queue, &desc, &dds.data);
+
+            (texture, desc.size, format)
+        } else {
+            let img = image::load(buf_reader, image_format)
+                .unwrap_or_else(|e| panic!("Unable to decode {}: {:?}", path.display(), e))
+                .to_rgba8();
+
+            let (texture, size) = self.upload_rgba(&img, Some(&label), space);
+            (texture, size, rgba_format(space))
+        };
+
+        self.add_image(texture, size, Some(format))
+    }
+
+    /// Load a skybox from six square image files, see [`Context::add_skybox_from_faces`].
+    pub fn load_skybox<P: AsRef<Path>>(&mut self, paths: [P; 6]) -> super::Skybox {
+        let images = paths
+            .iter()
+            .map(|path_ref| {
+                let path = path_ref.as_ref();
+                image::open(path)
+                    .unwrap_or_else(|e| panic!("Unable to load {}: {:?}", path.display(), e))
+                    .to_rgba8()
+            })
+            .collect::<Vec<_>>();
+        let size = images[0].width();
+        for img in images.iter() {
+            assert_eq!(
+                img.dimensions(),
+                (size, size),
+                "Skybox faces must be equal squares"
+            );
+        }
+        self.add_skybox_from_faces(
+            size,
+            [
+                &images[0], &images[1], &images[2], &images[3], &images[4], &images[5],
+            ],
+        )
+    }
+
+    /// Load a skybox from an equirectangular Radiance HDR image,
+    /// with the middle of the image looking towards -Z.
+    pub fn load_skybox_hdr(&mut self, path_ref: impl AsRef<Path>) -> super::Skybox {
+        let path = path_ref.as_ref();
+        let file = File::open(path)
+            .unwrap_or_else(|e| panic!("Unable to open {}: {:?}", path.display(), e));
+        let decoder = image::codecs::hdr::HdrDecoder::new(io::BufReader::new(file))
+            .unwrap_or_else(|e| panic!("Unable to read {}: {:?}", path.display(), e));
+        let meta = decoder.metadata();
+        let pixels = decoder
+            .read_image_hdr()
+            .unwrap_or_else(|e| panic!("Unable to decode {}: {:?}", path.display(), e));
+
+        let size = (meta.height / 2).max(1);
+        let mut data = Vec::with_capacity(6 * (size * size * 8) as usize);
+        for face in 0..6 {
+            for y in 0..size {
+                for x in 0..size {
+                    let u = 2.0 * (x as f32 + 0.5) / size as f32 - 1.0;
+                    let v = 2.0 * (y as f32 + 0.5) / size as f32 - 1.0;
+                    let dir = cube_direction(face, u, v);
+                    let color = sample_equirect(&pixels, meta.width, meta.height, dir);
+                    for &c in color.iter().chain(&[1.0]) {
+                        data.extend_from_slice(&f32_to_f16(c).to_le_bytes());
+                    }
+                }
+            }
+        }
+        self.add_cube_image(size, wgpu::TextureFormat::Rgba16Float, &data)
+    }
+
+    fn upload_rgba(
+        &self,
+        img: &image::RgbaImage,
+        label: Option<&str>,
+        space: ColorSpace,
+    ) -> (wgpu::Texture, wgpu::Extent3d) {
+        let (width, height) = img.dimensions();
+        let size = wgpu::Extent3d {
+            width,
+            height,
+            depth_or_array_layers: 1,
+        };
+        let desc = wgpu::TextureDescriptor {
+            label,
+            size,
+            mip_level_count: 1, //TODO: generate `size.max_mips()` mipmaps
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: rgba_format(space),
+            usage: wgpu::TextureUsages::COPY_DST | wgpu::TextureUsages::TEXTURE_BINDING,
+        };
+        let texture = self.device.create_texture(&desc);
+
+        self.queue.write_texture(
+            texture.as_image_copy(),
+            img,
+            wgpu::ImageDataLayout {
+                offset: 0,
+                bytes_per_row: std::num::NonZeroU32::new(width * 4),
+                rows_per_image: None,
+            },
+            size,
+        );
+        (texture, size)
+    }
+}
+
+/// Direction of a texel of a cube map face, with `u` and `v` in -1..1,
+/// and `v` going down. Faces are in the order +X, -X, +Y, -Y, +Z, -Z.
+fn cube_direction(face: usize, u: f32, v: f32) -> [f32; 3] {
+    match face {
+        0 => [1.0, -v, -u],
+        1 => [-1.0, -v, u],
+        2 => [u, 1.0, v],
+        3 => [u, -1.0, -v],
+        4 => [u, -v, 1.0],
+        _ => [-u, -v, -1.0],
+    }
+}
+
+/// Bilinear sample of an equirectangular image in the given direction,
+/// with -Z in the middle and +Y at the top.
+fn sample_equirect(pixels: &[image::Rgb<f32>], width: u32, height: u32, dir: [f32; 3]) -> [f32; 3] {
+    let length = (dir[0] * dir[0] + dir[1] * dir[1] + dir[2] * dir[2]).sqrt();
+    let longitude = dir[0].atan2(-dir[2]);
+    let latitude = (dir[1] / length).asin();
+    let x = (0.5 + longitude / (2.0 * std::f32::consts::PI)) * width as f32 - 0.5;
+    let y = ((0.5 - latitude / std::f32::consts::PI) * height as f32 - 0.5).max(0.0);
+    let (x0, y0) = (x.floor(), y.floor());
+    let (fx, fy) = (x - x0, y - y0);
+    let texel = |x: i64, y: i64| {
+        let xw = x.rem_euclid(width as i64) as usize;
+        let yc = y.min(height as i64 - 1) as usize;
+        pixels[yc * width as usize + xw].0
+    };
+    let (x0, y0) = (x0 as i64, y0 as i64);
+    let mut result = [0.0; 3];
+    for (c, r) in result.iter_mut().enumerate() {
+        let top = texel(x0, y0)[c] * (1.0 - fx) + texel(x0 + 1, y0)[c] * fx;
+        let bottom = texel(x0, y0 + 1)[c] * (1.0 - fx) + texel(x0 + 1, y0 + 1)[c] * fx;
+        *r = top * (1.0 - fy) + bottom * fy;
+    }
+    result
+}
+
+/// Conversion to a half-float, flushing tiny values to zero.
+fn f32_to_f16(value: f32) -> u16 {
+    let bits = value.to_bits();
+    let sign = ((bits >> 16) & 0x8000) as u16;
+    if value.is_nan() {
+        return sign | 0x7E00;
+    }
+    let exponent = ((bits >> 23) & 0xFF) as i32 - 127 + 15;
+    if exponent >= 0x1F {
+        sign | 0x7C00
+    } else if exponent <= 0 {
+        sign
+    } else {
+        sign | ((exponent as u16) << 10) | ((bits & 0x7F_FFFF) >> 13) as u16
+    }
+}
+
+fn rgba_format(space: ColorSpace) -> wgpu::TextureFormat {
+    match space {
+        ColorSpace::Srgb => wgpu::TextureFormat::Rgba8UnormSrgb,
+        ColorSpace::Linear => wgpu::TextureFormat::Rgba8Unorm,
+    }
+}
@@ -22,17 +22,26 @@
     clippy::pattern_type_mismatch,
 )]
 
-mod color;
+#[cfg(feature = "image")]
+mod capture;
+pub mod color;
+#[cfg(feature = "image")]
+mod decode;
 mod load;
 mod mesh;
+#[cfg(feature = "serde")]
+mod serialization;
 mod space;
 
 use raw_window_handle::HasRawWindowHandle;
-use std::{mem, ops};
+use std::{any, mem, ops};
 
-pub use color::Color;
+pub use color::{BlendMode, Color, ColorParseError, Gradient, LinearColor};
+pub use load::ColorSpace;
 pub use mesh::{IndexStream, Mesh, MeshBuilder, Prototype, Vertex, VertexStream};
-pub use space::{Camera, Projection, RawSpace};
+#[cfg(feature = "serde")]
+pub use serialization::{SceneLoadError, SceneRegistry};
+pub use space::{Aabb, Camera, Projection, RawSpace, Transform};
 
 pub trait HasWindow: HasRawWindowHandle {
     fn size(&self) -> mint::Vector2<u32>;
@@ -41,15 +50,70 @@ pub trait HasWindow: HasRawWindowHandle {
 struct SurfaceContext {
     raw: wgpu::Surface,
     config: wgpu::SurfaceConfiguration,
+    sample_count: u32,
+    depth: Option<wgpu::Texture>,
+    msaa: Option<wgpu::Texture>,
+}
+
+impl SurfaceContext {
+    fn size(&self) -> wgpu::Extent3d {
+        wgpu::Extent3d {
+            width: self.config.width,
+            height: self.config.height,
+            depth_or_array_layers: 1,
+        }
+    }
+
+    fn configure(&mut self, device: &wgpu::Device, depth: bool) {
+        self.raw.configure(device, &self.config);
+        if depth {
+            self.depth = Some(create_depth_texture(device, self.size(), self.sample_count));
+        }
+        if self.sample_count > 1 {
+            self.msaa = Some(device.create_texture(&wgpu::TextureDescriptor {
+                label: Some("msaa"),
+                size: self.size(),
+                mip_level_count: 1,
+                sample_count: self.sample_count,
+                dimension: wgpu::TextureDimension::D2,
+                format: self.config.format,
+                usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+            }));
+        }
+    }
 }
 
 pub struct Target {
+    /// View to render into, which is multisampled if `sample_count > 1`.
     pub view: wgpu::TextureView,
+    /// View that multisampled rendering needs to resolve into.
+    pub resolve_target: Option<wgpu::TextureView>,
     pub format: wgpu::TextureFormat,
     pub size: wgpu::Extent3d,
+    pub sample_count: u32,
+    /// Depth buffer of [`Target::DEPTH_FORMAT`] provided by the context, if enabled.
+    pub depth: Option<wgpu::TextureView>,
+}
+
+fn create_depth_texture(
+    device: &wgpu::Device,
+    size: wgpu::Extent3d,
+    sample_count: u32,
+) -> wgpu::Texture {
+    device.create_texture(&wgpu::TextureDescriptor {
+        label: Some("depth"),
+        size,
+        mip_level_count: 1,
+        sample_count,
+        dimension: wgpu::TextureDimension::D2,
+        format: Target::DEPTH_FORMAT,
+        usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+    })
 }
 
 impl Target {
+    pub const DEPTH_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Depth32Float;
+
     pub fn aspect(&self) -> f32 {
         self.size.width as f32 / self.size.height as f32
     }
@@ -63,36 +127,160 @@ pub struct TargetInfo {
     pub aspect_ratio: f32,
 }
 
+impl TargetInfo {
+    /// Info for the targets of [`Context::render_to_texture`].
+    pub fn offscreen(size: wgpu::Extent3d) -> Self {
+        Self {
+            format: Context::OFFSCREEN_FORMAT,
+            sample_count: 1,
+            aspect_ratio: size.width as f32 / size.height as f32,
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct TargetRef(u8);
 
 pub struct Image {
     pub view: wgpu::TextureView,
     pub size: wgpu::Extent3d,
+    /// Format of the texture, unknown for [`Context::add_image_from_raw`].
+    pub format: Option<wgpu::TextureFormat>,
 }
 
 pub struct ImageInfo {
     pub size: mint::Vector2<i16>,
+    pub format: Option<wgpu::TextureFormat>,
 }
 
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub struct ImageRef(u32);
 
+/// Cube map drawn behind everything else, see [`Camera::skybox`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Skybox {
+    /// Image with a cube view of the six faces.
+    pub image: ImageRef,
+}
+
+/// Fill behind the scene, see [`Camera::background`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Background {
+    Solid(Color),
+    /// Vertical blend from the bottom of the screen to the top.
+    LinearGradient {
+        top: Color,
+        bottom: Color,
+    },
+    /// Blend from the center of the screen to the corners.
+    Radial {
+        inner: Color,
+        outer: Color,
+    },
+}
+
+impl Default for Background {
+    fn default() -> Self {
+        Self::Solid(Color::default())
+    }
+}
+
+impl From<Color> for Background {
+    fn from(color: Color) -> Self {
+        Self::Solid(color)
+    }
+}
+
+impl Background {
+    /// Color to clear the target with, before any gradient is drawn on top.
+    pub fn clear_color(&self) -> Color {
+        match *self {
+            Self::Solid(color) => color,
+            Self::LinearGradient { bottom, .. } => bottom,
+            Self::Radial { outer, .. } => outer,
+        }
+    }
+}
+
 pub struct Context {
     #[allow(unused)]
     instance: wgpu::Instance,
     surface: Option<SurfaceContext>,
+    adapter_info: wgpu::AdapterInfo,
     device: wgpu::Device,
     queue: wgpu::Queue,
+    depth: bool,
     targets: Vec<Target>,
     images: Vec<Image>,
     meshes: Vec<Mesh>,
 }
 
+/// Error creating a [`Context`].
+#[derive(Debug)]
+pub enum ContextError {
+    /// No adapter matches the requested options.
+    NoAdapter,
+    /// The adapter doesn't support some of the requested features.
+    UnsupportedFeatures(wgpu::Features),
+    /// The adapter failed to provide a device.
+    DeviceRequest(wgpu::RequestDeviceError),
+    /// The adapter can't present to the window surface.
+    IncompatibleSurface,
+}
+
+impl std::fmt::Display for ContextError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::NoAdapter => write!(f, "no suitable adapter found"),
+            Self::UnsupportedFeatures(features) => {
+                write!(f, "adapter doesn't support features {:?}", features)
+            }
+            Self::DeviceRequest(ref e) => write!(f, "device request failed: {}", e),
+            Self::IncompatibleSurface => write!(f, "surface is incompatible with the adapter"),
+        }
+    }
+}
+
+impl std::error::Error for ContextError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match *self {
+            Self::DeviceRequest(ref e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Default, Debug)]
 pub struct ContextBuilder {
     power_preference: wgpu::PowerPreference,
     software: bool,
+    features: wgpu::Features,
+    limits: wgpu::Limits,
+    backends: Option<wgpu::Backends>,
+    present_mode: Option<wgpu::PresentMode>,
+    no_depth: bool,
+    sample_count: Option<u32>,
+}
+
+/// Parse a comma-separated list of backend names, as in `BARYON_BACKEND=vulkan,gl`.
+fn parse_backends(list: &str) -> wgpu::Backends {
+    list.split(',')
+        .map(|name| match name.trim().to_lowercase().as_str() {
+            "vulkan" | "vk" => wgpu::Backends::VULKAN,
+            "metal" | "mtl" => wgpu::Backends::METAL,
+            "dx12" | "d3d12" => wgpu::Backends::DX12,
+            "dx11" | "d3d11" => wgpu::Backends::DX11,
+            "gl" | "gles" | "opengl" => wgpu::Backends::GL,
+            "webgpu" => wgpu::Backends::BROWSER_WEBGPU,
+            "primary" => wgpu::Backends::PRIMARY,
+            "secondary" => wgpu::Backends::SECONDARY,
+            "all" => wgpu::Backends::all(),
+            other => {
+                log::warn!("Unknown backend {:?}", other);
+                wgpu::Backends::empty()
+            }
+        })
+        .fold(wgpu::Backends::empty(), |all, b| all | b)
 }
 
 impl ContextBuilder {
@@ -111,35 +299,144 @@ impl ContextBuilder {
         Self { software, ..self }
     }
 
-    pub async fn build_offscreen(self) -> Context {
-        let instance = wgpu::Instance::new(wgpu::Backends::PRIMARY);
+    /// Device features to request. Building fails if the adapter lacks any of them.
+    pub fn features(self, features: wgpu::Features) -> Self {
+        Self { features, ..self }
+    }
+
+    /// Device limits to request.
+    pub fn limits(self, limits: wgpu::Limits) -> Self {
+        Self { limits, ..self }
+    }
+
+    /// Backends to consider, `PRIMARY` by default.
+    ///
+    /// The `BARYON_BACKEND` environment variable takes precedence when set,
+    /// e.g. `BARYON_BACKEND=vulkan` or `BARYON_BACKEND=gl`.
+    pub fn backends(self, backends: wgpu::Backends) -> Self {
+        Self {
+            backends: Some(backends),
+            ..self
+        }
+    }
+
+    /// Presentation mode of the window surface, `Fifo` (vsync) by default.
+    ///
+    /// Baryon doesn't check the mode itself, since wgpu 0.11 can't list
+    /// the supported ones. wgpu-core falls back to `Fifo` with a logged
+    /// warning when it configures a surface that doesn't support the mode.
+    pub fn present_mode(self, present_mode: wgpu::PresentMode) -> Self {
+        Self {
+            present_mode: Some(present_mode),
+            ..self
+        }
+    }
+
+    /// Whether the context provides depth buffers for its targets, enabled by default.
+    ///
+    /// Purely 2D applications can disable it to save memory.
+    pub fn depth(self, enabled: bool) -> Self {
+        Self {
+            no_depth: !enabled,
+            ..self
+        }
+    }
+
+    /// Number of samples per pixel of the window surface, 1 by default.
+    ///
+    /// The count can be 1, 2, 4, or 8, and other values are clamped down.
+    /// WebGPU only guarantees 1 and 4, so unless the adapter supports
+    /// `TEXTURE_ADAPTER_SPECIFIC_FORMAT_FEATURES`, 2 becomes 1 and 8 becomes 4,
+    /// with a warning.
+    pub fn sample_count(self, sample_count: u32) -> Self {
+        Self {
+            sample_count: Some(sample_count),
+            ..self
+        }
+    }
+
+    fn valid_sample_count(&self, adapter: &wgpu::Adapter) -> u32 {
+        let requested = self.sample_count.unwrap_or(1);
+        let specific = adapter
+            .features()
+            .contains(wgpu::Features::TEXTURE_ADAPTER_SPECIFIC_FORMAT_FEATURES);
+        let count = match requested {
+            0 | 1 => 1,
+            2 | 3 if specific => 2,
+            2 | 3 => 1,
+            4..=7 => 4,
+            _ if specific => 8,
+            _ => 4,
+        };
+        if count != requested {
+            log::warn!(
+                "Sample count {} is not supported, using {}",
+                requested,
+                count
+            );
+        }
+        count
+    }
+
+    fn create_instance(&self) -> wgpu::Instance {
+        let backends = match std::env::var("BARYON_BACKEND") {
+            Ok(list) => parse_backends(&list),
+            Err(_) => self.backends.unwrap_or(wgpu::Backends::PRIMARY),
+        };
+        wgpu::Instance::new(backends)
+    }
+
+    async fn request_device(
+        &self,
+        instance: &wgpu::Instance,
+        surface: Option<&wgpu::Surface>,
+    ) -> Result<(wgpu::Adapter, wgpu::Device, wgpu::Queue), ContextError> {
         let adapter = instance
             .request_adapter(&wgpu::RequestAdapterOptions {
                 power_preference: self.power_preference,
                 force_fallback_adapter: self.software,
-                compatible_surface: None,
+                compatible_surface: surface,
             })
             .await
-            .unwrap();
+            .ok_or(ContextError::NoAdapter)?;
+
+        let missing = self.features - adapter.features();
+        if !missing.is_empty() {
+            return Err(ContextError::UnsupportedFeatures(missing));
+        }
 
+        let desc = wgpu::DeviceDescriptor {
+            label: None,
+            features: self.features,
+            limits: self.limits.clone(),
+        };
         let (device, queue) = adapter
-            .request_device(&wgpu::DeviceDescriptor::default(), None)
+            .request_device(&desc, None)
             .await
-            .unwrap();
+            .map_err(ContextError::DeviceRequest)?;
 
-        Context {
+        Ok((adapter, device, queue))
+    }
+
+    pub async fn build_offscreen(self) -> Result<Context, ContextError> {
+        let instance = self.create_instance();
+        let (adapter, device, queue) = self.request_device(&instance, None).await?;
+
+        Ok(Context {
             instance,
             surface: None,
+            adapter_info: adapter.get_info(),
             device,
             queue,
+            depth: !self.no_depth,
             targets: Vec::new(),
             images: Vec::new(),
             meshes: Vec::new(),
-        }
+        })
     }
 
-    pub async fn build<W: HasWindow>(self, window: &W) -> Context {
-        let instance = wgpu::Instance::new(wgpu::Backends::PRIMARY);
+    pub async fn build<W: HasWindow>(self, window: &W) -> Result<Context, ContextError> {
+        let instance = self.create_instance();
 
         let size = window.size();
         let mut surface = SurfaceContext {
@@ -150,36 +447,53 @@ impl ContextBuilder {
                 format: wgpu::TextureFormat::Depth24Plus,
                 width: size.x,
                 height: size.y,
-                present_mode: wgpu::PresentMode::Mailbox,
+                present_mode: self.present_mode.unwrap_or(wgpu::PresentMode::Fifo),
             },
+            sample_count: 1,
+            depth: None,
+            msaa: None,
         };
 
-        let adapter = instance
-            .request_adapter(&wgpu::RequestAdapterOptions {
-                power_preference: self.power_preference,
-                force_fallback_adapter: self.software,
-                compatible_surface: Some(&surface.raw),
-            })
-            .await
-            .unwrap();
-
-        let (device, queue) = adapter
-            .request_device(&wgpu::DeviceDescriptor::default(), None)
-            .await
-            .unwrap();
+        let (adapter, device, queue) = self.request_device(&instance, Some(&surface.raw)).await?;
 
-        let format = surface.raw.get_preferred_format(&adapter).unwrap();
+        let format = surface
+            .raw
+            .get_preferred_format(&adapter)
+            .ok_or(ContextError::IncompatibleSurface)?;
         surface.config.format = format;
-        surface.raw.configure(&device, &surface.config);
+        surface.sample_count = self.valid_sample_count(&adapter);
+        surface.configure(&device, !self.no_depth);
 
-        Context {
+        Ok(Context {
             instance,
             surface: Some(surface),
+            adapter_info: adapter.get_info(),
             device,
             queue,
+            depth: !self.no_depth,
             targets: Vec::new(),
             images: Vec::new(),
             meshes: Vec::new(),
+        })
+    }
+
+    /// Blocking version of [`ContextBuilder::build`].
+    #[cfg(feature = "pollster")]
+    pub fn build_blocking<W: HasWindow>(self, window: &W) -> Result<Context, ContextError> {
+        pollster::block_on(self.build(window))
+    }
+
+    /// Blocking version of [`ContextBuilder::build_offscreen`].
+    #[cfg(feature = "pollster")]
+    pub fn build_offscreen_blocking(self) -> Result<Context, ContextError> {
+        pollster::block_on(self.build_offscreen())
+    }
+
+    /// Same as [`ContextBuilder::build`], but panics on failure.
+    pub async fn build_or_panic<W: HasWindow>(self, window: &W) -> Context {
+        match self.build(window).await {
+            Ok(context) => context,
+            Err(e) => panic!("Unable to create the context: {}", e),
         }
     }
 }
@@ -189,51 +503,151 @@ impl Context {
         ContextBuilder::default()
     }
 
+    /// Information about the adapter selected when building the context.
+    pub fn adapter_info(&self) -> &wgpu::AdapterInfo {
+        &self.adapter_info
+    }
+
+    /// The underlying device, for creating custom GPU resources.
+    pub fn device(&self) -> &wgpu::Device {
+        &self.device
+    }
+
+    /// The underlying queue, for submitting custom work.
+    pub fn queue(&self) -> &wgpu::Queue {
+        &self.queue
+    }
+
+    /// Reconfigure the window surface for the new size.
+    ///
+    /// Zero sizes, e.g. of a minimized window, are ignored.
     pub fn resize(&mut self, width: u32, height: u32) {
         let surface = match self.surface {
             Some(ref mut suf) => suf,
             None => return,
         };
+        if width == 0 || height == 0 {
+            return;
+        }
         if (surface.config.width, surface.config.height) == (width, height) {
             return;
         }
         surface.config.width = width;
         surface.config.height = height;
-        surface.raw.configure(&self.device, &surface.config);
+        surface.configure(&self.device, self.depth);
     }
 
-    pub fn present<P: Pass>(&mut self, pass: &mut P, scene: &Scene, camera: &Camera) {
+    /// Draw a frame with the pass and present it to the window surface.
+    ///
+    /// If the surface is lost or outdated, it's reconfigured and the frame is skipped.
+    /// Only running out of memory is reported as an error.
+    pub fn present<P: Pass>(
+        &mut self,
+        pass: &mut P,
+        scene: &Scene,
+        camera: &Camera,
+    ) -> Result<(), wgpu::SurfaceError> {
         let surface = self.surface.as_mut().expect("No screen is configured!");
-        let frame = surface.raw.get_current_texture().unwrap();
-        let view = frame
+        let frame = match surface.raw.get_current_texture() {
+            Ok(frame) => frame,
+            Err(wgpu::SurfaceError::Lost) | Err(wgpu::SurfaceError::Outdated) => {
+                surface.configure(&self.device, self.depth);
+                return Ok(());
+            }
+            Err(wgpu::SurfaceError::Timeout) => {
+                log::warn!("Timed out acquiring a frame");
+                return Ok(());
+            }
+            Err(e) => return Err(e),
+        };
+        let frame_view = frame
             .texture
             .create_view(&wgpu::TextureViewDescriptor::default());
+        let (view, resolve_target) = match surface.msaa {
+            Some(ref msaa) => (
+                msaa.create_view(&wgpu::TextureViewDescriptor::default()),
+                Some(frame_view),
+            ),
+            None => (frame_view, None),
+        };
 
         let tr = TargetRef(self.targets.len() as _);
         self.targets.push(Target {
             view,
+            resolve_target,
             format: surface.config.format,
-            size: wgpu::Extent3d {
-                width: surface.config.width,
-                height: surface.config.height,
-                depth_or_array_layers: 1,
-            },
+            size: surface.size(),
+            sample_count: surface.sample_count,
+            depth: surface
+                .depth
+                .as_ref()
+                .map(|t| t.create_view(&wgpu::TextureViewDescriptor::default())),
         });
 
         pass.draw(&[tr], scene, camera, self);
 
         self.targets.pop();
         frame.present();
+        Ok(())
+    }
+
+    /// Format of the textures produced by [`Context::render_to_texture`].
+    pub const OFFSCREEN_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Rgba8Unorm;
+
+    /// Render into a new texture, without needing a window.
+    ///
+    /// The pass has to be created for [`TargetInfo::offscreen`].
+    /// The texture can be sampled or copied from afterwards.
+    pub fn render_to_texture<P: Pass>(
+        &mut self,
+        pass: &mut P,
+        scene: &Scene,
+        camera: &Camera,
+        size: wgpu::Extent3d,
+    ) -> wgpu::Texture {
+        let texture = self.device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("offscreen"),
+            size,
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: Self::OFFSCREEN_FORMAT,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT
+                | wgpu::TextureUsages::COPY_SRC
+                | wgpu::TextureUsages::TEXTURE_BINDING,
+        });
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+        let tr = TargetRef(self.targets.len() as _);
+        let depth = if self.depth {
+            let texture = create_depth_texture(&self.device, size, 1);
+            Some(texture.create_view(&wgpu::TextureViewDescriptor::default()))
+        } else {
+            None
+        };
+        self.targets.push(Target {
+            view,
+            resolve_target: None,
+            format: Self::OFFSCREEN_FORMAT,
+            size,
+            sample_count: 1,
+            depth,
+        });
+
+        pass.draw(&[tr], scene, camera, self);
+
+        self.targets.pop();
+        texture
     }
 
-    pub fn add_mesh(&mut self) -> MeshBuilder {
+    pub fn add_mesh(&mut self) -> MeshBuilder<'_> {
         MeshBuilder::new(self)
     }
 
     pub fn surface_info(&self) -> Option<TargetInfo> {
         self.surface.as_ref().map(|s| TargetInfo {
             format: s.config.format,
-            sample_count: 1,
+            sample_count: s.sample_count,
             aspect_ratio: s.config.width as f32 / s.config.height as f32,
         })
     }
@@ -242,6 +656,7 @@ impl Context {
         let image = &self.images[image_ref.0 as usize];
         ImageInfo {
             size: [image.size.width as i16, image.size.height as i16].into(),
+            format: image.format,
         }
     }
 }
@@ -286,10 +701,52 @@ pub trait Pass {
 #[derive(Clone, Copy, Debug, Default, PartialEq)]
 pub struct NodeRef(u32);
 
+/// What to preserve when moving a node under another parent.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ParentMode {
+    /// Keep the local transform, so the node moves along with the new parent.
+    KeepLocal,
+    /// Keep the world transform, recomputing the local one.
+    KeepWorld,
+}
+
+/// Error changing the parent of a node.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ParentError {
+    /// The root node can't have a parent.
+    Root,
+    /// The new parent is the node itself, or one of its descendants.
+    Cycle,
+    /// The new parent is out of range, or was removed.
+    Missing,
+}
+
+impl std::fmt::Display for ParentError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::Root => write!(f, "the root node can't be reparented"),
+            Self::Cycle => write!(f, "a node can't be parented under its own descendant"),
+            Self::Missing => write!(f, "the new parent doesn't exist"),
+        }
+    }
+}
+
+impl std::error::Error for ParentError {}
+
+impl NodeRef {
+    /// Position of the node in [`Scene::resolve_world_transforms`].
+    pub fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
 #[derive(Default, Debug, PartialEq)]
 pub struct Node {
     parent: NodeRef,
-    local: space::Space,
+    local: Transform,
+    children: Vec<NodeRef>,
+    name: Option<String>,
+    hidden: bool,
 }
 
 pub type EntityRef = hecs::Entity;
@@ -297,9 +754,26 @@ pub type EntityRef = hecs::Entity;
 pub struct Array<T>(Vec<T>);
 
 pub struct Scene {
-    pub world: hecs::World,
+    world: hecs::World,
     pub nodes: Array<Node>,
     pub lights: Array<Light>,
+    free_nodes: Vec<NodeRef>,
+    lines: Vec<Line>,
+    /// Prototypes of the meshes used by entities, for copying them.
+    prototypes: Vec<Prototype>,
+    cloners: Vec<(any::TypeId, Cloner)>,
+}
+
+type Cloner = fn(&hecs::World, EntityRef, &mut hecs::EntityBuilder);
+
+fn clone_component<T: hecs::Component + Clone>(
+    world: &hecs::World,
+    entity: EntityRef,
+    builder: &mut hecs::EntityBuilder,
+) {
+    if let Ok(component) = world.get::<T>(entity) {
+        builder.add(T::clone(&component));
+    }
 }
 
 impl ops::Index<NodeRef> for Array<Node> {
@@ -338,6 +812,15 @@ impl ops::IndexMut<LightRef> for Array<Light> {
 
 pub struct BakedScene {
     spaces: Box<[RawSpace]>,
+    visible: Box<[bool]>,
+}
+
+impl BakedScene {
+    /// Check if the node and all of its ancestors are visible,
+    /// see [`Scene::is_visible`].
+    pub fn is_visible(&self, node: NodeRef) -> bool {
+        self.visible[node.0 as usize]
+    }
 }
 
 impl ops::Index<NodeRef> for BakedScene {
@@ -356,6 +839,7 @@ pub struct LightBuilder {
     color: Color,
     intensity: f32,
     kind: LightKind,
+    range: f32,
 }
 
 pub struct SpriteBuilder {
@@ -370,16 +854,388 @@ impl Scene {
             world: Default::default(),
             nodes: Array(vec![Node::default()]),
             lights: Array(Vec::new()),
+            free_nodes: Vec::new(),
+            lines: Vec::new(),
+            prototypes: Vec::new(),
+            cloners: vec![
+                (any::TypeId::of::<Color>(), clone_component::<Color>),
+                (any::TypeId::of::<Aabb>(), clone_component::<Aabb>),
+            ],
+        }
+    }
+
+    /// Local transform of the node, relative to its parent.
+    pub fn transform(&self, node: NodeRef) -> Transform {
+        self[node].local
+    }
+
+    /// Local transform of the node, relative to its parent.
+    ///
+    /// Panics if the node doesn't belong to this scene.
+    pub fn node(&self, node: NodeRef) -> &Transform {
+        match self.nodes.0.get(node.0 as usize) {
+            Some(n) => &n.local,
+            None => panic!("Node {:?} is out of range", node),
         }
     }
 
+    /// Mutable local transform of the node, for animating it after it's built.
+    ///
+    /// Panics if the node doesn't belong to this scene.
+    pub fn node_mut(&mut self, node: NodeRef) -> &mut Transform {
+        match self.nodes.0.get_mut(node.0 as usize) {
+            Some(n) => &mut n.local,
+            None => panic!("Node {:?} is out of range", node),
+        }
+    }
+
+    pub fn set_transform(&mut self, node: NodeRef, transform: Transform) {
+        *self.node_mut(node) = transform;
+    }
+
+    pub fn set_position(&mut self, node: NodeRef, position: impl Into<mint::Vector3<f32>>) {
+        self.node_mut(node).position = position.into();
+    }
+
+    pub fn set_orientation(
+        &mut self,
+        node: NodeRef,
+        orientation: impl Into<mint::Quaternion<f32>>,
+    ) {
+        self.node_mut(node).orientation = orientation.into();
+    }
+
+    pub fn set_scale(&mut self, node: NodeRef, scale: impl Into<mint::Vector3<f32>>) {
+        self.node_mut(node).scale = scale.into();
+    }
+
+    /// Move the node under a new parent.
+    ///
+    /// Fails if the node is the root, if the parent is the node or one of
+    /// its descendants, or if the parent doesn't exist or was removed.
+    pub fn set_parent(
+        &mut self,
+        node: NodeRef,
+        parent: NodeRef,
+        mode: ParentMode,
+    ) -> Result<(), ParentError> {
+        if node == NodeRef::default() {
+            return Err(ParentError::Root);
+        }
+        if parent.0 as usize >= self.nodes.0.len() || self.free_nodes.contains(&parent) {
+            return Err(ParentError::Missing);
+        }
+        let mut ancestor = parent;
+        for _ in 0..self.nodes.0.len() {
+            if ancestor == node {
+                return Err(ParentError::Cycle);
+            }
+            if ancestor == NodeRef::default() {
+                break;
+            }
+            ancestor = self[ancestor].parent;
+        }
+        if mode == ParentMode::KeepWorld {
+            // the root's own transform doesn't apply to its children
+            let parent_world = if parent == NodeRef::default() {
+                Transform::identity()
+            } else {
+                self.world_transform(parent)
+            };
+            self[node].local = parent_world.inverse() * self.world_transform(node);
+        }
+        self.detach(node);
+        self[node].parent = parent;
+        self[parent].children.push(node);
+        Ok(())
+    }
+
+    /// Remove the node from the child list of its parent.
+    fn detach(&mut self, node: NodeRef) {
+        let parent = self[node].parent;
+        let siblings = &mut self[parent].children;
+        if let Some(pos) = siblings.iter().position(|&child| child == node) {
+            siblings.remove(pos);
+        }
+    }
+
+    /// Direct children of the node, in the order they were attached to it.
+    ///
+    /// Nodes moved under a new parent, either by [`Scene::set_parent`] or
+    /// by [`Scene::remove_node`], go after the existing children.
+    pub fn children(&self, node: NodeRef) -> impl Iterator<Item = NodeRef> + '_ {
+        self[node].children.iter().copied()
+    }
+
+    /// All the nodes under the node, not including itself, in depth-first
+    /// pre-order: each node comes before its children, and the children
+    /// follow the order of [`Scene::children`].
+    pub fn descendants(&self, node: NodeRef) -> impl Iterator<Item = NodeRef> + '_ {
+        let mut stack: Vec<NodeRef> = self[node].children.iter().rev().copied().collect();
+        std::iter::from_fn(move || {
+            let next = stack.pop()?;
+            stack.extend(self[next].children.iter().rev());
+            Some(next)
+        })
+    }
+
+    /// Entities and sprites attached to the node or any of its descendants,
+    /// in the order they are stored in the world.
+    pub fn entities_under(&self, node: NodeRef) -> impl Iterator<Item = EntityRef> {
+        let mut inside = vec![false; self.nodes.0.len()];
+        inside[node.0 as usize] = true;
+        for nr in self.descendants(node) {
+            inside[nr.0 as usize] = true;
+        }
+        let mut entities = Vec::new();
+        for (e, entity) in self.world.query::<&Entity>().iter() {
+            if inside[entity.node.0 as usize] {
+                entities.push(e);
+            }
+        }
+        for (e, sprite) in self.world.query::<&Sprite>().iter() {
+            if inside[sprite.node.0 as usize] {
+                entities.push(e);
+            }
+        }
+        entities.into_iter()
+    }
+
+    /// Name given to the node when it was built.
+    pub fn node_name(&self, node: NodeRef) -> Option<&str> {
+        self[node].name.as_deref()
+    }
+
+    /// All the nodes with the given name, in the order of their indices.
+    ///
+    /// Removed nodes lose their names, so a reused slot only answers
+    /// to the name of the node occupying it.
+    pub fn find_all<'a>(&'a self, name: &'a str) -> impl Iterator<Item = NodeRef> + 'a {
+        self.nodes
+            .0
+            .iter()
+            .enumerate()
+            .filter(move |&(_, node)| node.name.as_deref() == Some(name))
+            .map(|(index, _)| NodeRef(index as u32))
+    }
+
+    /// First node with the given name, see [`Scene::find_all`].
+    pub fn find_node(&self, name: &str) -> Option<NodeRef> {
+        self.find_all(name).next()
+    }
+
+    /// An entity or a sprite whose node has the given name.
+    pub fn find_entity(&self, name: &str) -> Option<EntityRef> {
+        let named = |node: NodeRef| self[node].name.as_deref() == Some(name);
+        for (e, entity) in self.world.query::<&Entity>().iter() {
+            if named(entity.node) {
+                return Some(e);
+            }
+        }
+        for (e, sprite) in self.world.query::<&Sprite>().iter() {
+            if named(sprite.node) {
+                return Some(e);
+            }
+        }
+        None
+    }
+
+    /// Node of an entity or a sprite, if it has one.
+    pub fn entity_node(&self, entity: EntityRef) -> Option<NodeRef> {
+        if let Ok(e) = self.world.get::<Entity>(entity) {
+            Some(e.node)
+        } else if let Ok(sprite) = self.world.get::<Sprite>(entity) {
+            Some(sprite.node)
+        } else {
+            None
+        }
+    }
+
+    /// Show or hide the node of an entity or a sprite, along with everything attached below it.
+    pub fn set_visible(&mut self, entity: EntityRef, visible: bool) {
+        match self.entity_node(entity) {
+            Some(node) => self.set_node_visible(node, visible),
+            None => log::warn!("Entity {:?} has no node to hide", entity),
+        }
+    }
+
+    /// Show or hide the node along with its subtree.
+    ///
+    /// A visible node under a hidden one stays hidden, until
+    /// all of its ancestors are shown again.
+    pub fn set_node_visible(&mut self, node: NodeRef, visible: bool) {
+        self[node].hidden = !visible;
+    }
+
+    /// Check if the node and all of its ancestors are visible.
+    /// Render passes skip the entities, sprites, and lights of hidden nodes.
+    pub fn is_visible(&self, node: NodeRef) -> bool {
+        let mut current = node;
+        for _ in 0..self.nodes.0.len() {
+            if self[current].hidden {
+                return false;
+            }
+            if current == NodeRef::default() {
+                return true;
+            }
+            current = self[current].parent;
+        }
+        log::warn!("Node {:?} has a cycle in its parent chain", node);
+        true
+    }
+
+    /// Transform of the node relative to the scene root, composed from its ancestors.
+    ///
+    /// Panics if the parent chain has a cycle, the same as
+    /// [`Scene::resolve_world_transforms`].
+    pub fn world_transform(&self, node: NodeRef) -> Transform {
+        // Collect the ancestors below the root, which doesn't affect its children.
+        let mut chain = vec![node];
+        let mut current = self[node].parent;
+        while current != NodeRef::default() {
+            assert!(
+                chain.len() < self.nodes.0.len(),
+                "Node {:?} has a cycle in its parent chain",
+                node
+            );
+            chain.push(current);
+            current = self[current].parent;
+        }
+        // Compose from top to bottom, the same way as `bake` does.
+        let top = self[chain.pop().unwrap()].local;
+        chain
+            .iter()
+            .rev()
+            .fold(top, |world, &nr| world * self[nr].local)
+    }
+
+    /// Position of the node relative to the scene root.
+    pub fn world_position(&self, node: NodeRef) -> mint::Vector3<f32> {
+        self.world_transform(node).position
+    }
+
+    /// Column-major local-to-world matrix of the node, see [`Scene::world_transform`].
+    pub fn world_matrix(&self, node: NodeRef) -> mint::ColumnMatrix4<f32> {
+        self.world_transform(node).to_matrix()
+    }
+
+    /// Rotate the node about the `pivot` point in the space of its parent,
+    /// see [`Transform::rotate_around`].
+    pub fn rotate_node_around(
+        &mut self,
+        node: NodeRef,
+        pivot: impl Into<mint::Vector3<f32>>,
+        rotation: impl Into<mint::Quaternion<f32>>,
+    ) {
+        let local = self.node_mut(node);
+        *local = local.rotate_around(pivot, rotation);
+    }
+
+    /// Map a point from the space of the node into world space.
+    pub fn transform_point(&self, node: NodeRef, point: mint::Vector3<f32>) -> mint::Vector3<f32> {
+        self.world_transform(node).transform_point(point)
+    }
+
+    /// Map a direction from the space of the node into world space.
+    pub fn transform_vector(
+        &self,
+        node: NodeRef,
+        vector: mint::Vector3<f32>,
+    ) -> mint::Vector3<f32> {
+        self.world_transform(node).transform_vector(vector)
+    }
+
     fn add_node_impl(&mut self, node: &mut Node) -> NodeRef {
-        let index = self.nodes.0.len();
-        self.nodes.0.push(mem::take(node));
-        NodeRef(index as u32)
+        let parent = node.parent;
+        let nr = if let Some(nr) = self.free_nodes.pop() {
+            self.nodes.0[nr.0 as usize] = mem::take(node);
+            nr
+        } else {
+            let index = self.nodes.0.len();
+            self.nodes.0.push(mem::take(node));
+            NodeRef(index as u32)
+        };
+        self[parent].children.push(nr);
+        nr
+    }
+
+    /// Remove the entity and its components from the scene, and its node
+    /// from the hierarchy, see [`Scene::remove_node`].
+    pub fn despawn(&mut self, entity: EntityRef) -> Result<(), hecs::NoSuchEntity> {
+        let node = self.entity_node(entity);
+        self.world.despawn(entity)?;
+        match node {
+            Some(node) if node != NodeRef::default() => self.remove_node(node),
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Remove the entity together with all the nodes under its node,
+    /// and the entities, sprites, and lights attached to them.
+    pub fn despawn_recursive(&mut self, entity: EntityRef) -> Result<(), hecs::NoSuchEntity> {
+        let top = match self.entity_node(entity) {
+            Some(node) if node != NodeRef::default() => node,
+            _ => return self.despawn(entity),
+        };
+        let entities: Vec<_> = self.entities_under(top).collect();
+        let subtree: Vec<_> = std::iter::once(top).chain(self.descendants(top)).collect();
+        self.detach(top);
+        for &node in subtree.iter() {
+            self[node] = Node::default();
+            self.free_nodes.push(node);
+        }
+        self.remove_lights(&subtree);
+        for e in entities {
+            self.world.despawn(e)?;
+        }
+        Ok(())
+    }
+
+    /// Remove a node from the hierarchy.
+    ///
+    /// Children of the node are attached to its parent, keeping their world
+    /// transforms. Other nodes keep their indices, but the removed `NodeRef`
+    /// becomes invalid, and its slot is reused by the next node added.
+    /// Lights attached to the node are removed along with it, but entities
+    /// and sprites end up attached to that new node, so they should be
+    /// despawned first.
+    ///
+    /// Panics if the node is the root, or is already removed.
+    pub fn remove_node(&mut self, node: NodeRef) {
+        assert_ne!(node, NodeRef::default(), "Unable to remove the root node");
+        assert!(
+            !self.free_nodes.contains(&node),
+            "Node {:?} is already removed",
+            node
+        );
+        self.detach(node);
+        let removed = mem::take(&mut self[node]);
+        for &child in removed.children.iter() {
+            let local = removed.local * self[child].local;
+            self[child].local = local;
+            self[child].parent = removed.parent;
+        }
+        self[removed.parent].children.extend(removed.children);
+        self.free_nodes.push(node);
+        self.remove_lights(&[node]);
     }
 
-    pub fn add_node(&mut self) -> ObjectBuilder<()> {
+    /// Mark the lights attached to freed nodes as removed, so that
+    /// they don't follow the nodes that reuse the slots.
+    fn remove_lights(&mut self, nodes: &[NodeRef]) {
+        let mut freed = vec![false; self.nodes.0.len()];
+        for &node in nodes {
+            freed[node.0 as usize] = true;
+        }
+        for light in self.lights.0.iter_mut() {
+            if freed[light.node.0 as usize] {
+                light.removed = true;
+            }
+        }
+    }
+
+    pub fn add_node(&mut self) -> ObjectBuilder<'_, ()> {
         ObjectBuilder {
             scene: self,
             node: Node::default(),
@@ -387,9 +1243,125 @@ impl Scene {
         }
     }
 
-    pub fn add_entity(&mut self, prototype: &Prototype) -> ObjectBuilder<EntityBuilder> {
+    fn remember_prototype(&mut self, prototype: &Prototype) {
+        if !self
+            .prototypes
+            .iter()
+            .any(|p| p.reference == prototype.reference)
+        {
+            self.prototypes.push(prototype.clone());
+        }
+    }
+
+    /// Let [`Scene::instantiate`] copy components of this type when
+    /// this scene is used as a prefab. [`Color`] is always copied.
+    pub fn register_clone<T: hecs::Component + Clone>(&mut self) {
+        let id = any::TypeId::of::<T>();
+        if !self.cloners.iter().any(|&(other, _)| other == id) {
+            self.cloners.push((id, clone_component::<T>));
+        }
+    }
+
+    /// Copy the nodes, lights, and entities of the `prefab` scene into
+    /// this one, under a new node with the given transform.
+    ///
+    /// The new node stands for the prefab root, and the copied nodes keep
+    /// their names, transforms, and parent links, with entities and lights
+    /// attached to the copies. Entities get their mesh and sprite components,
+    /// and whatever components were registered with [`Scene::register_clone`]
+    /// on the prefab, and this scene learns those registrations as well.
+    /// Returns the new node.
+    pub fn instantiate(&mut self, prefab: &Scene, root_transform: Transform) -> NodeRef {
+        let root = self.add_node_impl(&mut Node {
+            local: root_transform,
+            hidden: prefab[NodeRef::default()].hidden,
+            ..Node::default()
+        });
+        let mut remap = vec![None; prefab.nodes.0.len()];
+        remap[0] = Some(root);
+        // parents are visited before their children
+        for node in prefab.descendants(NodeRef::default()) {
+            let original = &prefab[node];
+            let mut copy = Node {
+                parent: remap[original.parent.0 as usize].unwrap(),
+                local: original.local,
+                children: Vec::new(),
+                name: original.name.clone(),
+                hidden: original.hidden,
+            };
+            remap[node.0 as usize] = Some(self.add_node_impl(&mut copy));
+        }
+
+        for (_, light) in prefab.lights() {
+            if let Some(node) = remap[light.node.0 as usize] {
+                self.lights.0.push(Light {
+                    node,
+                    color: light.color,
+                    intensity: light.intensity,
+                    kind: light.kind,
+                    range: light.range,
+                    removed: false,
+                });
+            }
+        }
+
+        let mut builder = hecs::EntityBuilder::new();
+        for entity_ref in prefab.world.iter() {
+            let e = entity_ref.entity();
+            builder.clear();
+            if let Ok(entity) = prefab.world.get::<Entity>(e) {
+                let node = match remap[entity.node.0 as usize] {
+                    Some(node) => node,
+                    None => continue,
+                };
+                match prefab
+                    .prototypes
+                    .iter()
+                    .find(|p| p.reference == entity.mesh)
+                {
+                    Some(prototype) => {
+                        builder.add_bundle(prototype);
+                        self.remember_prototype(prototype);
+                    }
+                    None => log::warn!("Prototype of {:?} is unknown", entity.mesh),
+                }
+                builder.add(Entity {
+                    node,
+                    mesh: entity.mesh,
+                });
+            }
+            if let Ok(sprite) = prefab.world.get::<Sprite>(e) {
+                let node = match remap[sprite.node.0 as usize] {
+                    Some(node) => node,
+                    None => continue,
+                };
+                builder.add(Sprite {
+                    node,
+                    image: sprite.image,
+                    uv: sprite.uv.clone(),
+                });
+            }
+            for &(_, cloner) in prefab.cloners.iter() {
+                cloner(&prefab.world, e, &mut builder);
+            }
+            self.world.spawn(builder.build());
+        }
+
+        for &(id, cloner) in prefab.cloners.iter() {
+            if !self.cloners.iter().any(|&(other, _)| other == id) {
+                self.cloners.push((id, cloner));
+            }
+        }
+        root
+    }
+
+    pub fn add_entity(&mut self, prototype: &Prototype) -> ObjectBuilder<'_, EntityBuilder> {
+        self.remember_prototype(prototype);
         let mut raw = hecs::EntityBuilder::new();
         raw.add_bundle(prototype);
+        if let Some(bounds) = prototype.bounds {
+            raw.add(bounds);
+        }
         ObjectBuilder {
             scene: self,
             node: Node::default(),
@@ -400,7 +1372,7 @@ impl Scene {
         }
     }
 
-    pub fn add_sprite(&mut self, image: ImageRef) -> ObjectBuilder<SpriteBuilder> {
+    pub fn add_sprite(&mut self, image: ImageRef) -> ObjectBuilder<'_, SpriteBuilder> {
         let raw = hecs::EntityBuilder::new();
         ObjectBuilder {
             scene: self,
@@ -413,52 +1385,213 @@ impl Scene {
         }
     }
 
-    pub fn add_light(&mut self, kind: LightKind) -> ObjectBuilder<LightBuilder> {
+    pub fn add_light(&mut self, kind: LightKind) -> ObjectBuilder<'_, LightBuilder> {
         ObjectBuilder {
             scene: self,
             node: Node::default(),
             kind: LightBuilder {
-                color: Color(0xFFFFFFFF),
+                color: Color::WHITE,
                 intensity: 1.0,
                 kind,
+                range: f32::INFINITY,
             },
         }
     }
 
-    pub fn add_directional_light(&mut self) -> ObjectBuilder<LightBuilder> {
+    pub fn add_directional_light(&mut self) -> ObjectBuilder<'_, LightBuilder> {
         self.add_light(LightKind::Directional)
     }
 
-    pub fn add_point_light(&mut self) -> ObjectBuilder<LightBuilder> {
+    pub fn add_point_light(&mut self) -> ObjectBuilder<'_, LightBuilder> {
         self.add_light(LightKind::Point)
     }
 
+    /// Iterate over the entities matching the query, see [`hecs::World::query`].
+    pub fn query<Q: hecs::Query>(&self) -> hecs::QueryBorrow<'_, Q> {
+        self.world.query::<Q>()
+    }
+
+    /// Iterate over the entities matching the query with unique access to the scene,
+    /// see [`hecs::World::query_mut`].
+    pub fn query_mut<Q: hecs::Query>(&mut self) -> hecs::QueryMut<'_, Q> {
+        self.world.query_mut::<Q>()
+    }
+
+    /// Component of a single entity, see [`hecs::World::get`].
+    pub fn get<T: hecs::Component>(
+        &self,
+        entity: EntityRef,
+    ) -> Result<hecs::Ref<'_, T>, hecs::ComponentError> {
+        self.world.get::<T>(entity)
+    }
+
+    /// Mutable component of a single entity, see [`hecs::World::get_mut`].
+    pub fn get_mut<T: hecs::Component>(
+        &self,
+        entity: EntityRef,
+    ) -> Result<hecs::RefMut<'_, T>, hecs::ComponentError> {
+        self.world.get_mut::<T>(entity)
+    }
+
+    /// Add a component to an existing entity, replacing the previous one of the same type.
+    pub fn insert<T: hecs::Component>(
+        &mut self,
+        entity: EntityRef,
+        component: T,
+    ) -> Result<(), hecs::NoSuchEntity> {
+        self.world.insert_one(entity, component)
+    }
+
+    /// Take a component away from an entity.
+    pub fn remove<T: hecs::Component>(
+        &mut self,
+        entity: EntityRef,
+    ) -> Result<T, hecs::ComponentError> {
+        self.world.remove_one::<T>(entity)
+    }
+
+    /// The underlying ECS world, for anything not covered by the methods above.
+    pub fn world(&self) -> &hecs::World {
+        &self.world
+    }
+
+    /// Mutable access to the underlying ECS world.
+    ///
+    /// Despawning entities through it leaves their nodes in the hierarchy,
+    /// prefer [`Scene::despawn`] for that.
+    pub fn world_mut(&mut self) -> &mut hecs::World {
+        &mut self.world
+    }
+
+    /// Add a line segment in world space, to be drawn by passes that
+    /// support lines, like the solid one.
+    ///
+    /// Lines accumulate until [`Scene::clear_lines`] is called, which
+    /// is normally done once per frame, before adding the new ones.
+    pub fn draw_line(
+        &mut self,
+        start: impl Into<mint::Vector3<f32>>,
+        end: impl Into<mint::Vector3<f32>>,
+        color: Color,
+    ) {
+        self.lines.push(Line {
+            start: start.into(),
+            end: end.into(),
+            color,
+        });
+    }
+
+    pub fn lines(&self) -> &[Line] {
+        &self.lines
+    }
+
+    pub fn clear_lines(&mut self) {
+        self.lines.clear();
+    }
+
+    /// World-space box enclosing the [`Aabb`] components of all the
+    /// visible entities and sprites, or `None` if there are none.
+    pub fn bounds(&self) -> Option<Aabb> {
+        let spaces = self.resolve_world_transforms();
+        let visible = self.resolve_visibility();
+        let mut bounds: Option<Aabb> = None;
+        for (_, (aabb, entity, sprite)) in self
+            .world
+            .query::<(&Aabb, Option<&Entity>, Option<&Sprite>)>()
+            .iter()
+        {
+            let node = match (entity, sprite) {
+                (Some(entity), _) => entity.node,
+                (None, Some(sprite)) => sprite.node,
+                (None, None) => continue,
+            };
+            if !visible[node.0 as usize] {
+                continue;
+            }
+            let world = aabb.transformed(&spaces[node.0 as usize]);
+            bounds = Some(match bounds {
+                Some(b) => b.union(&world),
+                None => world,
+            });
+        }
+        bounds
+    }
+
+    /// Lights of the scene, without the ones whose nodes were removed.
     pub fn lights<'a>(&'a self) -> impl Iterator<Item = (LightRef, &'a Light)> {
         self.lights
             .0
             .iter()
             .enumerate()
+            .filter(|&(_, light)| !light.removed)
             .map(|(i, light)| (LightRef(i as u32), light))
     }
 
-    pub fn bake(&self) -> BakedScene {
-        let mut spaces: Vec<RawSpace> = Vec::with_capacity(self.nodes.0.len());
-        for n in self.nodes.0.iter() {
-            let space = if n.parent == NodeRef::default() {
-                n.local.clone()
-            } else {
-                let parent_space = spaces[n.parent.0 as usize].to_space();
-                parent_space.combine(&n.local)
-            };
-            spaces.push(space.into());
+    /// World transforms of all the nodes, indexed by [`NodeRef::index`],
+    /// as computed by [`Scene::world_transform`] for each of them.
+    ///
+    /// Panics if the parent chain of any node has a cycle.
+    pub fn resolve_world_transforms(&self) -> Vec<Transform> {
+        // Reused slots may come before their parents, so resolve on demand.
+        let mut spaces = vec![None; self.nodes.0.len()];
+        for index in 0..spaces.len() {
+            self.resolve_node(index, &mut spaces, 0);
+        }
+        spaces.into_iter().map(Option::unwrap).collect()
+    }
+
+    /// Visibility of all the nodes, indexed by [`NodeRef::index`],
+    /// as computed by [`Scene::is_visible`] for each of them.
+    pub fn resolve_visibility(&self) -> Vec<bool> {
+        let mut visible = vec![true; self.nodes.0.len()];
+        let mut stack = vec![NodeRef::default()];
+        visible[0] = !self[NodeRef::default()].hidden;
+        while let Some(node) = stack.pop() {
+            for &child in self[node].children.iter() {
+                visible[child.0 as usize] = visible[node.0 as usize] && !self[child].hidden;
+                stack.push(child);
+            }
+        }
+        visible
+    }
+
+    fn resolve_node(
+        &self,
+        index: usize,
+        spaces: &mut [Option<Transform>],
+        depth: usize,
+    ) -> Transform {
+        if let Some(space) = spaces[index] {
+            return space;
         }
+        assert!(
+            depth < spaces.len(),
+            "Node {} has a cycle in its parent chain",
+            index
+        );
+        let n = &self.nodes.0[index];
+        let space = if n.parent == NodeRef::default() {
+            n.local
+        } else {
+            self.resolve_node(n.parent.0 as usize, spaces, depth + 1) * n.local
+        };
+        spaces[index] = Some(space);
+        space
+    }
+
+    pub fn bake(&self) -> BakedScene {
         BakedScene {
-            spaces: spaces.into_boxed_slice(),
+            spaces: self
+                .resolve_world_transforms()
+                .into_iter()
+                .map(RawSpace::from)
+                .collect(),
+            visible: self.resolve_visibility().into_boxed_slice(),
         }
     }
 }
 
-#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
 pub struct MeshRef(u32);
 
 pub struct Entity {
@@ -475,6 +1608,7 @@ pub struct Sprite {
 }
 
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum LightKind {
     Directional,
     Point,
@@ -483,12 +1617,25 @@ pub enum LightKind {
 #[derive(Clone, Copy, Debug, Default, PartialEq)]
 pub struct LightRef(u32);
 
+/// Line segment in world space, see [`Scene::draw_line`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Line {
+    pub start: mint::Vector3<f32>,
+    pub end: mint::Vector3<f32>,
+    pub color: Color,
+}
+
 #[derive(Debug)]
 pub struct Light {
     pub node: NodeRef,
     pub color: Color,
     pub intensity: f32,
     pub kind: LightKind,
+    /// Distance beyond which a point light has no effect.
+    pub range: f32,
+    /// Set when the node is removed. The slot isn't reused,
+    /// so that other `LightRef` values stay valid.
+    removed: bool,
 }
 
 pub struct ObjectBuilder<'a, T> {
@@ -498,10 +1645,42 @@ pub struct ObjectBuilder<'a, T> {
 }
 
 impl<T> ObjectBuilder<'_, T> {
+    /// Attach to a previously built node.
+    ///
+    /// Panics if the node doesn't belong to this scene, or is already removed.
     pub fn parent(&mut self, parent: NodeRef) -> &mut Self {
+        assert!(
+            (parent.0 as usize) < self.scene.nodes.0.len(),
+            "Parent {:?} is out of range of {} nodes",
+            parent,
+            self.scene.nodes.0.len()
+        );
+        assert!(
+            !self.scene.free_nodes.contains(&parent),
+            "Node {:?} is already removed",
+            parent
+        );
         self.node.parent = parent;
         self
     }
+
+    /// Name the node, for looking it up with [`Scene::find_node`].
+    /// Names don't have to be unique.
+    pub fn name(&mut self, name: impl Into<String>) -> &mut Self {
+        self.node.name = Some(name.into());
+        self
+    }
+
+    /// Start out hidden, see [`Scene::set_node_visible`].
+    pub fn hidden(&mut self) -> &mut Self {
+        self.visible(false)
+    }
+
+    /// Start out visible or hidden, see [`Scene::set_node_visible`].
+    pub fn visible(&mut self, visible: bool) -> &mut Self {
+        self.node.hidden = !visible;
+        self
+    }
 }
 
 impl ObjectBuilder<'_, ()> {
@@ -522,11 +1701,7 @@ impl ObjectBuilder<'_, EntityBuilder> {
 
     pub fn build(&mut self) -> EntityRef {
         let entity = Entity {
-            node: if self.node.local == space::Space::default() {
-                self.node.parent
-            } else {
-                self.scene.add_node_impl(&mut self.node)
-            },
+            node: self.scene.add_node_impl(&mut self.node),
             mesh: self.kind.mesh,
         };
         let built = self.kind.raw.add(entity).build();
@@ -548,11 +1723,7 @@ impl ObjectBuilder<'_, SpriteBuilder> {
 
     pub fn build(&mut self) -> EntityRef {
         let sprite = Sprite {
-            node: if self.node.local == space::Space::default() {
-                self.node.parent
-            } else {
-                self.scene.add_node_impl(&mut self.node)
-            },
+            node: self.scene.add_node_impl(&mut self.node),
             image: self.kind.image,
             uv: self.kind.uv.take(),
         };
@@ -572,16 +1743,26 @@ impl ObjectBuilder<'_, LightBuilder> {
         self
     }
 
+    /// Limit the distance affected by a point light, which is unlimited by default.
+    ///
+    /// Point lights fall off with the square of the distance,
+    /// and fade out smoothly towards the range.
+    ///
+    /// Panics if the range isn't positive.
+    pub fn range(&mut self, range: f32) -> &mut Self {
+        assert!(range > 0.0, "Light range {} has to be positive", range);
+        self.kind.range = range;
+        self
+    }
+
     pub fn build(&mut self) -> LightRef {
         let light = Light {
-            node: if self.node.local == space::Space::default() {
-                self.node.parent
-            } else {
-                self.scene.add_node_impl(&mut self.node)
-            },
+            node: self.scene.add_node_impl(&mut self.node),
             color: self.kind.color,
             intensity: self.kind.intensity,
             kind: self.kind.kind,
+            range: self.kind.range,
+            removed: false,
         };
         let index = self.scene.lights.0.len();
         self.scene.lights.0.push(light);
@@ -1,14 +1,40 @@
-use std::{any::TypeId, marker::PhantomData, mem};
+use std::{
+    any::TypeId,
+    marker::PhantomData,
+    mem,
+    sync::atomic::{AtomicU32, Ordering},
+};
 use wgpu::util::DeviceExt as _;
 
 /// A freshly created Mesh that comes with metadata,
 /// which is necessary to instantiate it.
+#[derive(Clone)]
 pub struct Prototype {
     pub reference: super::MeshRef,
+    /// Bounds of the vertex positions, added to the entities as a component.
+    pub bounds: Option<super::Aabb>,
     type_ids: Box<[TypeId]>,
     type_infos: Box<[hecs::TypeInfo]>,
 }
 
+impl Prototype {
+    /// Prototype without any mesh data behind it, for entities that are
+    /// never drawn, like in headless tests of the scene logic.
+    ///
+    /// Every call returns a distinct `MeshRef` that no [`Context`](super::Context)
+    /// knows about. The entities don't have vertex streams, so the passes skip them.
+    pub fn placeholder() -> Self {
+        // count down, so that the meshes of a context never collide with these
+        static NEXT: AtomicU32 = AtomicU32::new(u32::MAX);
+        Self {
+            reference: super::MeshRef(NEXT.fetch_sub(1, Ordering::Relaxed)),
+            bounds: None,
+            type_ids: Box::new([]),
+            type_infos: Box::new([]),
+        }
+    }
+}
+
 pub struct IndexStream {
     pub offset: wgpu::BufferAddress,
     pub format: wgpu::IndexFormat,
@@ -32,9 +58,9 @@ unsafe impl<'a> hecs::DynamicBundle for &'a Prototype {
     unsafe fn put(self, mut f: impl FnMut(*mut u8, hecs::TypeInfo)) {
         const DUMMY_SIZE: usize = 1;
         let mut v = [0u8; DUMMY_SIZE];
-        assert!(mem::size_of::<Vertex<()>>() <= DUMMY_SIZE);
+        assert!(size_of::<Vertex<()>>() <= DUMMY_SIZE);
         for ts in self.type_infos.iter() {
-            f(v.as_mut_ptr(), ts.clone());
+            f(v.as_mut_ptr(), *ts);
         }
     }
 }
@@ -54,7 +80,7 @@ impl Mesh {
             .find(|vs| vs.type_id == TypeId::of::<T>())
     }
 
-    pub fn vertex_slice<T: 'static>(&self) -> wgpu::BufferSlice {
+    pub fn vertex_slice<T: 'static>(&self) -> wgpu::BufferSlice<'_> {
         let stream = self.vertex_stream::<T>().unwrap();
         self.buffer.slice(stream.offset..)
     }
@@ -71,6 +97,7 @@ pub struct MeshBuilder<'a> {
     type_infos: Vec<hecs::TypeInfo>,
     vertex_count: usize,
     bound_radius: f32,
+    bounds: Option<super::Aabb>,
 }
 
 impl<'a> MeshBuilder<'a> {
@@ -84,6 +111,7 @@ impl<'a> MeshBuilder<'a> {
             type_infos: Vec::new(),
             vertex_count: 0,
             bound_radius: 0.0,
+            bounds: None,
         }
     }
 
@@ -119,7 +147,7 @@ impl<'a> MeshBuilder<'a> {
         self.vertex_streams.push(VertexStream {
             type_id: TypeId::of::<T>(),
             offset,
-            stride: mem::size_of::<T>() as _,
+            stride: size_of::<T>() as _,
         });
         self.type_infos.push(hecs::TypeInfo::of::<Vertex<T>>());
         self
@@ -130,6 +158,12 @@ impl<'a> MeshBuilder<'a> {
         self
     }
 
+    /// Set the bounding box of the vertex positions.
+    pub fn bounds(&mut self, bounds: super::Aabb) -> &mut Self {
+        self.bounds = Some(bounds);
+        self
+    }
+
     pub fn build(&mut self) -> Prototype {
         let index = self.context.meshes.len();
 
@@ -164,6 +198,7 @@ impl<'a> MeshBuilder<'a> {
 
         Prototype {
             reference: super::MeshRef(index as u32),
+            bounds: self.bounds.take(),
             type_ids,
             type_infos: mem::take(&mut self.type_infos).into_boxed_slice(),
         }
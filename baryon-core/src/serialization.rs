@@ -0,0 +1,328 @@
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::{collections::BTreeMap, fmt, io};
+
+use super::{Entity, Light, Node, NodeRef, Scene, Sprite, Transform};
+
+type SaveFn = fn(&hecs::World, hecs::Entity) -> Option<serde_json::Result<serde_json::Value>>;
+type LoadFn = fn(&mut hecs::EntityBuilder, serde_json::Value) -> serde_json::Result<()>;
+
+struct ComponentEntry {
+    name: String,
+    save: SaveFn,
+    load: LoadFn,
+}
+
+fn save_component<T: hecs::Component + Serialize>(
+    world: &hecs::World,
+    entity: hecs::Entity,
+) -> Option<serde_json::Result<serde_json::Value>> {
+    let component = world.get::<T>(entity).ok()?;
+    Some(serde_json::to_value(&*component))
+}
+
+fn load_component<T: hecs::Component + DeserializeOwned>(
+    builder: &mut hecs::EntityBuilder,
+    value: serde_json::Value,
+) -> serde_json::Result<()> {
+    builder.add(serde_json::from_value::<T>(value)?);
+    Ok(())
+}
+
+/// Components and meshes known to [`Scene::save`] and [`Scene::load`].
+///
+/// Components are stored under the given names, and anything not registered
+/// is skipped on save. Meshes are referred to by name, since they live in the
+/// [`Context`](crate::Context), and entities with unregistered meshes are saved without them.
+pub struct SceneRegistry<'a> {
+    components: Vec<ComponentEntry>,
+    meshes: Vec<(String, &'a super::Prototype)>,
+}
+
+impl<'a> SceneRegistry<'a> {
+    /// Create a registry that knows about [`Color`](crate::Color).
+    pub fn new() -> Self {
+        let mut registry = Self {
+            components: Vec::new(),
+            meshes: Vec::new(),
+        };
+        registry.component::<super::Color>("color");
+        registry
+    }
+
+    /// Register a component type, replacing any other one with the same name.
+    pub fn component<T: hecs::Component + Serialize + DeserializeOwned>(
+        &mut self,
+        name: &str,
+    ) -> &mut Self {
+        self.components.retain(|entry| entry.name != name);
+        self.components.push(ComponentEntry {
+            name: name.to_string(),
+            save: save_component::<T>,
+            load: load_component::<T>,
+        });
+        self
+    }
+
+    /// Register a mesh prototype, for the entities built from it.
+    pub fn mesh(&mut self, name: &str, prototype: &'a super::Prototype) -> &mut Self {
+        self.meshes.retain(|entry| entry.0 != name);
+        self.meshes.push((name.to_string(), prototype));
+        self
+    }
+}
+
+/// Failure to load a scene with [`Scene::load`].
+#[derive(Debug)]
+pub enum SceneLoadError {
+    /// The data couldn't be read or isn't a valid scene.
+    Parse(serde_json::Error),
+    /// A component name isn't in the registry.
+    UnknownComponent(String),
+    /// A mesh name isn't in the registry.
+    UnknownMesh(String),
+    /// A node index is out of range, or a node comes before its parent.
+    InvalidNode(usize),
+}
+
+impl fmt::Display for SceneLoadError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Self::Parse(ref e) => write!(f, "invalid scene data: {}", e),
+            Self::UnknownComponent(ref name) => write!(f, "unknown component '{}'", name),
+            Self::UnknownMesh(ref name) => write!(f, "unknown mesh '{}'", name),
+            Self::InvalidNode(index) => write!(f, "invalid node index {}", index),
+        }
+    }
+}
+
+impl std::error::Error for SceneLoadError {}
+
+impl From<serde_json::Error> for SceneLoadError {
+    fn from(e: serde_json::Error) -> Self {
+        Self::Parse(e)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct NodeData {
+    parent: usize,
+    position: [f32; 3],
+    orientation: [f32; 4],
+    scale: [f32; 3],
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    hidden: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+struct LightData {
+    node: usize,
+    kind: super::LightKind,
+    color: super::Color,
+    intensity: f32,
+    /// Missing for infinite range, which JSON can't represent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    range: Option<f32>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct EntityData {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    node: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    mesh: Option<String>,
+    #[serde(default)]
+    components: BTreeMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct SceneData {
+    nodes: Vec<NodeData>,
+    lights: Vec<LightData>,
+    entities: Vec<EntityData>,
+}
+
+impl Scene {
+    /// Write the node hierarchy, lights, and entities as JSON.
+    ///
+    /// Nodes are stored depth-first, so their indices in the file differ
+    /// from the `NodeRef` values of this scene. Entity handles aren't stored
+    /// either, and sprites are skipped, since their images live in the context.
+    pub fn save<W: io::Write>(
+        &self,
+        registry: &SceneRegistry,
+        writer: W,
+    ) -> serde_json::Result<()> {
+        let order: Vec<NodeRef> = std::iter::once(NodeRef::default())
+            .chain(self.descendants(NodeRef::default()))
+            .collect();
+        let mut remap = vec![None; self.nodes.0.len()];
+        for (index, &node) in order.iter().enumerate() {
+            remap[node.0 as usize] = Some(index);
+        }
+
+        let nodes = order
+            .iter()
+            .map(|&node| {
+                let n = &self[node];
+                NodeData {
+                    parent: remap[n.parent.0 as usize].unwrap_or(0),
+                    position: n.local.position.into(),
+                    orientation: n.local.orientation.into(),
+                    scale: n.local.scale.into(),
+                    name: n.name.clone(),
+                    hidden: n.hidden,
+                }
+            })
+            .collect();
+
+        let mut lights = Vec::new();
+        for (_, light) in self.lights() {
+            match remap[light.node.0 as usize] {
+                Some(node) => lights.push(LightData {
+                    node,
+                    kind: light.kind,
+                    color: light.color,
+                    intensity: light.intensity,
+                    range: if light.range.is_finite() {
+                        Some(light.range)
+                    } else {
+                        None
+                    },
+                }),
+                None => log::warn!("Skipping a light attached to a removed node"),
+            }
+        }
+
+        let mut entities = Vec::new();
+        for entity_ref in self.world.iter() {
+            let e = entity_ref.entity();
+            if self.world.get::<Sprite>(e).is_ok() {
+                log::warn!("Sprite {:?} is not saved", e);
+                continue;
+            }
+            let mut data = EntityData {
+                node: None,
+                mesh: None,
+                components: BTreeMap::new(),
+            };
+            if let Ok(entity) = self.world.get::<Entity>(e) {
+                match registry
+                    .meshes
+                    .iter()
+                    .find(|entry| entry.1.reference == entity.mesh)
+                {
+                    Some(entry) => {
+                        data.node = remap[entity.node.0 as usize];
+                        data.mesh = Some(entry.0.clone());
+                    }
+                    None => log::warn!("Mesh {:?} of {:?} is not registered", entity.mesh, e),
+                }
+            }
+            for entry in registry.components.iter() {
+                if let Some(value) = (entry.save)(&self.world, e) {
+                    data.components.insert(entry.name.clone(), value?);
+                }
+            }
+            entities.push(data);
+        }
+
+        let data = SceneData {
+            nodes,
+            lights,
+            entities,
+        };
+        serde_json::to_writer_pretty(writer, &data)
+    }
+
+    /// Read a scene written by [`Scene::save`].
+    ///
+    /// Node indices from the file are remapped to the new nodes, and the
+    /// entities are spawned with new handles, in the order they were saved.
+    pub fn load<R: io::Read>(registry: &SceneRegistry, reader: R) -> Result<Self, SceneLoadError> {
+        let data: SceneData = serde_json::from_reader(reader)?;
+        let mut scene = Self::new();
+
+        let mut node_refs = Vec::with_capacity(data.nodes.len());
+        for (index, nd) in data.nodes.into_iter().enumerate() {
+            let local = Transform {
+                position: nd.position.into(),
+                orientation: nd.orientation.into(),
+                scale: nd.scale.into(),
+            };
+            if index == 0 {
+                scene[NodeRef::default()].local = local;
+                scene[NodeRef::default()].name = nd.name;
+                scene[NodeRef::default()].hidden = nd.hidden;
+                node_refs.push(NodeRef::default());
+                continue;
+            }
+            // parents are saved before their children
+            let parent = match node_refs.get(nd.parent) {
+                Some(&parent) => parent,
+                None => return Err(SceneLoadError::InvalidNode(nd.parent)),
+            };
+            let mut node = Node {
+                parent,
+                local,
+                children: Vec::new(),
+                name: nd.name,
+                hidden: nd.hidden,
+            };
+            node_refs.push(scene.add_node_impl(&mut node));
+        }
+        let node_ref = |index: usize| match node_refs.get(index) {
+            Some(&node) => Ok(node),
+            None => Err(SceneLoadError::InvalidNode(index)),
+        };
+
+        for ld in data.lights {
+            match ld.range {
+                Some(range) if range <= 0.0 => {
+                    return Err(SceneLoadError::Parse(serde::de::Error::custom(format!(
+                        "light range {} has to be positive",
+                        range
+                    ))))
+                }
+                _ => {}
+            }
+            scene.lights.0.push(Light {
+                node: node_ref(ld.node)?,
+                color: ld.color,
+                intensity: ld.intensity,
+                kind: ld.kind,
+                range: ld.range.unwrap_or(f32::INFINITY),
+                removed: false,
+            });
+        }
+
+        for ed in data.entities {
+            let mut builder = hecs::EntityBuilder::new();
+            if let Some(ref mesh) = ed.mesh {
+                let prototype = match registry.meshes.iter().find(|entry| entry.0 == *mesh) {
+                    Some(entry) => entry.1,
+                    None => return Err(SceneLoadError::UnknownMesh(mesh.clone())),
+                };
+                builder.add_bundle(prototype);
+                if let Some(bounds) = prototype.bounds {
+                    builder.add(bounds);
+                }
+                scene.remember_prototype(prototype);
+                builder.add(Entity {
+                    node: node_ref(ed.node.unwrap_or(0))?,
+                    mesh: prototype.reference,
+                });
+            }
+            for (name, value) in ed.components {
+                match registry.components.iter().find(|entry| entry.name == name) {
+                    Some(entry) => (entry.load)(&mut builder, value)?,
+                    None => return Err(SceneLoadError::UnknownComponent(name)),
+                }
+            }
+            scene.world.spawn(builder.build());
+        }
+
+        Ok(scene)
+    }
+}
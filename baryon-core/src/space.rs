@@ -1,173 +1,561 @@
 use std::ops;
 
-#[derive(Clone, Debug, PartialEq)]
-pub(super) struct Space {
-    position: glam::Vec3,
-    scale: f32,
-    orientation: glam::Quat,
+/// Position, per-axis scale, and orientation of a node relative to its parent.
+///
+/// Points are scaled first, then rotated, then translated.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Transform {
+    pub position: mint::Vector3<f32>,
+    pub scale: mint::Vector3<f32>,
+    pub orientation: mint::Quaternion<f32>,
 }
 
-impl Default for Space {
+impl Default for Transform {
     fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl Transform {
+    pub fn identity() -> Self {
         Self {
-            position: glam::Vec3::ZERO,
-            scale: 1.0,
-            orientation: glam::Quat::IDENTITY,
+            position: glam::Vec3::ZERO.into(),
+            scale: glam::Vec3::ONE.into(),
+            orientation: glam::Quat::IDENTITY.into(),
         }
     }
-}
 
-impl Space {
-    pub(super) fn combine(&self, other: &Self) -> Self {
+    pub fn from_position(position: impl Into<mint::Vector3<f32>>) -> Self {
         Self {
-            scale: self.scale * other.scale,
-            orientation: self.orientation * other.orientation,
-            position: self.scale * (self.orientation * other.position) + self.position,
+            position: position.into(),
+            ..Self::identity()
         }
     }
 
-    fn inverse(&self) -> Self {
-        let scale = 1.0 / self.scale;
-        let orientation = self.orientation.inverse();
-        let position = -scale * (orientation * self.position);
+    pub fn from_scale(scale: impl Into<mint::Vector3<f32>>) -> Self {
         Self {
-            position,
-            scale,
-            orientation,
+            scale: scale.into(),
+            ..Self::identity()
         }
     }
 
-    fn to_matrix(&self) -> glam::Mat4 {
+    pub fn from_uniform_scale(scale: f32) -> Self {
+        Self::from_scale(mint::Vector3::from([scale; 3]))
+    }
+
+    pub fn from_orientation(orientation: impl Into<mint::Quaternion<f32>>) -> Self {
+        Self {
+            orientation: orientation.into(),
+            ..Self::identity()
+        }
+    }
+
+    /// Create an orientation from Euler angles in radians.
+    ///
+    /// In the right-handed, Y-up coordinate system the rotation is applied as
+    /// `yaw` around Y, then `pitch` around the new X, then `roll` around the new Z.
+    pub fn from_euler(yaw: f32, pitch: f32, roll: f32) -> Self {
+        Self::from_orientation(mint::Quaternion::from(glam::Quat::from_euler(
+            glam::EulerRot::YXZ,
+            yaw,
+            pitch,
+            roll,
+        )))
+    }
+
+    /// Same as [`Transform::from_euler`], but with angles in degrees.
+    pub fn from_euler_deg(yaw: f32, pitch: f32, roll: f32) -> Self {
+        Self::from_euler(yaw.to_radians(), pitch.to_radians(), roll.to_radians())
+    }
+
+    /// Extract the yaw, pitch, and roll of [`Transform::from_euler`] in radians.
+    ///
+    /// The pitch is in `[-PI/2, PI/2]`.
+    pub fn to_euler(&self) -> (f32, f32, f32) {
+        self.glam_orientation().to_euler(glam::EulerRot::YXZ)
+    }
+
+    /// Same as [`Transform::to_euler`], but with angles in degrees.
+    pub fn to_euler_deg(&self) -> (f32, f32, f32) {
+        let (yaw, pitch, roll) = self.to_euler();
+        (yaw.to_degrees(), pitch.to_degrees(), roll.to_degrees())
+    }
+
+    /// Create a transform at `eye`, with the local -Z axis facing the `target`,
+    /// and the local +Y axis as close to `up` as possible.
+    ///
+    /// If the target matches the eye, the orientation is identity.
+    /// If `up` is parallel to the view direction, another up axis is picked.
+    pub fn look_at(
+        eye: impl Into<mint::Vector3<f32>>,
+        target: impl Into<mint::Vector3<f32>>,
+        up: impl Into<mint::Vector3<f32>>,
+    ) -> Self {
+        let eye = eye.into();
+        let eye_vec = glam::Vec3::from(eye);
+        let forward = (glam::Vec3::from(target.into()) - eye_vec).normalize_or_zero();
+        if forward == glam::Vec3::ZERO {
+            return Self::from_position(eye);
+        }
+        let mut right = forward.cross(glam::Vec3::from(up.into()));
+        if right.length_squared() < 1e-12 {
+            let other = if forward.z.abs() < 0.9 {
+                glam::Vec3::Z
+            } else {
+                glam::Vec3::X
+            };
+            right = forward.cross(other);
+        }
+        let right = right.normalize();
+        let true_up = right.cross(forward);
+        let rotation = glam::Mat3::from_cols(right, true_up, -forward);
+        Self {
+            position: eye,
+            orientation: glam::Quat::from_mat3(&rotation).into(),
+            ..Self::identity()
+        }
+    }
+
+    fn glam_position(&self) -> glam::Vec3 {
+        self.position.into()
+    }
+
+    fn glam_orientation(&self) -> glam::Quat {
+        self.orientation.into()
+    }
+
+    fn glam_scale(&self) -> glam::Vec3 {
+        self.scale.into()
+    }
+
+    /// Apply `other` in the space of this transform.
+    ///
+    /// The position of the result is always exact. A rotated child of a
+    /// non-uniformly scaled parent would need a shear to be represented
+    /// exactly, so in this case the scale is measured along the child's axes,
+    /// which is exact as long as the rotation maps the axes onto each other.
+    fn combine(&self, other: &Self) -> Self {
+        let orientation = self.glam_orientation();
+        let child_orientation = other.glam_orientation();
+        let scale = if self.scale.x == self.scale.y && self.scale.x == self.scale.z {
+            self.scale.x * other.glam_scale()
+        } else {
+            let axis_scale =
+                |axis: glam::Vec3| (self.glam_scale() * (child_orientation * axis)).length();
+            other.glam_scale()
+                * glam::Vec3::new(
+                    axis_scale(glam::Vec3::X),
+                    axis_scale(glam::Vec3::Y),
+                    axis_scale(glam::Vec3::Z),
+                )
+        };
+        Self {
+            scale: scale.into(),
+            orientation: (orientation * child_orientation).into(),
+            position: (orientation * (self.glam_scale() * other.glam_position())
+                + self.glam_position())
+            .into(),
+        }
+    }
+
+    fn glam_matrix(&self) -> glam::Mat4 {
         glam::Mat4::from_scale_rotation_translation(
-            glam::Vec3::splat(self.scale),
-            self.orientation,
-            self.position,
+            self.glam_scale(),
+            self.glam_orientation(),
+            self.glam_position(),
         )
     }
+
+    /// Column-major matrix of this transform, composed as
+    /// `translation * rotation * scale`, so that the scale is applied first.
+    pub fn to_matrix(self) -> mint::ColumnMatrix4<f32> {
+        self.glam_matrix().into()
+    }
+
+    /// Transform that undoes this one, so that `t * t.inverse()` is the identity.
+    ///
+    /// This is exact for uniform scale. A rotated non-uniform scale would
+    /// need to be applied after the rotation, which can't be represented,
+    /// so it's approximated the same way as in composition. A zero scale
+    /// component has no inverse and stays zero.
+    pub fn inverse(&self) -> Self {
+        let recip = |s: f32| if s == 0.0 { 0.0 } else { 1.0 / s };
+        let scale = glam::Vec3::new(
+            recip(self.scale.x),
+            recip(self.scale.y),
+            recip(self.scale.z),
+        );
+        let orientation = self.glam_orientation().conjugate();
+        Self {
+            position: (scale * (orientation * -self.glam_position())).into(),
+            scale: scale.into(),
+            orientation: orientation.into(),
+        }
+    }
+
+    /// Map a point from the local space into the parent space,
+    /// applying scale, rotation, and translation.
+    pub fn transform_point(&self, point: mint::Vector3<f32>) -> mint::Vector3<f32> {
+        (self.glam_orientation() * (self.glam_scale() * glam::Vec3::from(point))
+            + self.glam_position())
+        .into()
+    }
+
+    /// Map a direction from the local space into the parent space,
+    /// applying scale and rotation only.
+    pub fn transform_vector(&self, vector: mint::Vector3<f32>) -> mint::Vector3<f32> {
+        (self.glam_orientation() * (self.glam_scale() * glam::Vec3::from(vector))).into()
+    }
+
+    /// Unit direction of the local -Z axis, where cameras look.
+    pub fn forward(&self) -> mint::Vector3<f32> {
+        (self.glam_orientation() * -glam::Vec3::Z).into()
+    }
+
+    /// Unit direction of the local +X axis.
+    pub fn right(&self) -> mint::Vector3<f32> {
+        (self.glam_orientation() * glam::Vec3::X).into()
+    }
+
+    /// Unit direction of the local +Y axis.
+    pub fn up(&self) -> mint::Vector3<f32> {
+        (self.glam_orientation() * glam::Vec3::Y).into()
+    }
+
+    /// Rotate the transform about the `pivot` point, given in the same space
+    /// as the position. The rotation is applied on top of the current orientation.
+    ///
+    /// In the right-handed system, a quarter turn around +Y takes +X to -Z.
+    pub fn rotate_around(
+        &self,
+        pivot: impl Into<mint::Vector3<f32>>,
+        rotation: impl Into<mint::Quaternion<f32>>,
+    ) -> Self {
+        let pivot = glam::Vec3::from(pivot.into());
+        let rotation = glam::Quat::from(rotation.into());
+        Self {
+            position: (pivot + rotation * (self.glam_position() - pivot)).into(),
+            orientation: (rotation * self.glam_orientation()).into(),
+            scale: self.scale,
+        }
+    }
+
+    /// Blend between this transform at `t = 0` and `other` at `t = 1`.
+    ///
+    /// Position and scale are interpolated linearly, and the orientation
+    /// spherically along the shortest path. Values of `t` outside of `[0, 1]`
+    /// extrapolate instead of being clamped.
+    pub fn lerp(self, other: &Self, t: f32) -> Self {
+        let start = self.glam_orientation();
+        let mut end = other.glam_orientation();
+        let mut dot = start.dot(end);
+        if dot < 0.0 {
+            end = -end;
+            dot = -dot;
+        }
+        let orientation = if start == end {
+            start
+        } else if dot > 1.0 - 1e-6 {
+            // nearly identical, avoid dividing by a vanishing sine
+            glam::Quat::from_vec4(glam::Vec4::from(start).lerp(end.into(), t))
+        } else {
+            let theta = dot.acos();
+            let sin_theta = theta.sin();
+            let a = ((1.0 - t) * theta).sin() / sin_theta;
+            let b = (t * theta).sin() / sin_theta;
+            glam::Quat::from_vec4(glam::Vec4::from(start) * a + glam::Vec4::from(end) * b)
+        };
+        Self {
+            position: self.glam_position().lerp(other.glam_position(), t).into(),
+            scale: self.glam_scale().lerp(other.glam_scale(), t).into(),
+            orientation: orientation.normalize().into(),
+        }
+    }
+
+    /// Decompose a column-major affine matrix into a transform.
+    ///
+    /// Shear can't be represented, so it's dropped: the scale is the length
+    /// of each basis column, and the orientation is derived from the columns
+    /// after making them orthogonal. A matrix with a negative determinant
+    /// (a mirroring) gets its X scale negated. The projective row is ignored.
+    pub fn from_matrix(matrix: mint::ColumnMatrix4<f32>) -> Self {
+        let m = glam::Mat4::from(matrix);
+        let x = m.x_axis.truncate();
+        let y = m.y_axis.truncate();
+        let z = m.z_axis.truncate();
+        let mut scale = glam::Vec3::new(x.length(), y.length(), z.length());
+        if x.cross(y).dot(z) < 0.0 {
+            scale.x = -scale.x;
+        }
+        let orientation = if scale.x == 0.0 || scale.y == 0.0 || scale.z == 0.0 {
+            glam::Quat::IDENTITY
+        } else {
+            // Gram-Schmidt, starting from the X axis
+            let axis_x = (x / scale.x).normalize();
+            let axis_y = (y - axis_x * axis_x.dot(y)).normalize();
+            let axis_z = axis_x.cross(axis_y);
+            glam::Quat::from_mat3(&glam::Mat3::from_cols(axis_x, axis_y, axis_z)).normalize()
+        };
+        Self {
+            position: m.w_axis.truncate().into(),
+            scale: scale.into(),
+            orientation: orientation.into(),
+        }
+    }
+
+    /// Same as [`Transform::to_matrix`], as a glam matrix.
+    #[cfg(feature = "glam")]
+    pub fn to_glam(self) -> glam::Mat4 {
+        self.glam_matrix()
+    }
+
+    /// Same as [`Transform::from_matrix`], from a glam matrix.
+    #[cfg(feature = "glam")]
+    pub fn from_glam(matrix: glam::Mat4) -> Self {
+        Self::from_matrix(matrix.into())
+    }
+
+    /// Same as [`Transform::to_matrix`], flattened column by column,
+    /// ready to be uploaded into a uniform buffer.
+    pub fn to_matrix_bytes(self) -> [f32; 16] {
+        self.glam_matrix().to_cols_array()
+    }
+}
+
+/// Composition of a parent transform with a child one, matching the scene graph.
+impl ops::Mul for Transform {
+    type Output = Self;
+    fn mul(self, other: Self) -> Self {
+        self.combine(&other)
+    }
+}
+
+/// Axis-aligned bounding box, attached to entities as a component
+/// in the local space of their nodes.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Aabb {
+    pub min: mint::Vector3<f32>,
+    pub max: mint::Vector3<f32>,
+}
+
+impl Aabb {
+    /// Smallest box containing all the points, or `None` if there are none.
+    pub fn from_points<I>(points: I) -> Option<Self>
+    where
+        I: IntoIterator,
+        I::Item: Into<mint::Vector3<f32>>,
+    {
+        let mut iter = points.into_iter().map(|p| glam::Vec3::from(p.into()));
+        let first = iter.next()?;
+        let (min, max) = iter.fold((first, first), |(min, max), p| (min.min(p), max.max(p)));
+        Some(Self {
+            min: min.into(),
+            max: max.into(),
+        })
+    }
+
+    pub fn center(&self) -> mint::Vector3<f32> {
+        ((glam::Vec3::from(self.min) + glam::Vec3::from(self.max)) * 0.5).into()
+    }
+
+    /// Half of the size along each axis.
+    pub fn half_extent(&self) -> mint::Vector3<f32> {
+        ((glam::Vec3::from(self.max) - glam::Vec3::from(self.min)) * 0.5).into()
+    }
+
+    /// Smallest box containing both of the boxes.
+    pub fn union(&self, other: &Self) -> Self {
+        Self {
+            min: glam::Vec3::from(self.min)
+                .min(glam::Vec3::from(other.min))
+                .into(),
+            max: glam::Vec3::from(self.max)
+                .max(glam::Vec3::from(other.max))
+                .into(),
+        }
+    }
+
+    /// Axis-aligned box enclosing this one after the transform,
+    /// which is larger than the original if there is a rotation.
+    pub fn transformed(&self, transform: &Transform) -> Self {
+        let m = glam::Mat3::from_quat(transform.glam_orientation())
+            * glam::Mat3::from_diagonal(transform.glam_scale());
+        let center = m * glam::Vec3::from(self.center()) + transform.glam_position();
+        let half = glam::Vec3::from(self.half_extent());
+        let extent = m.x_axis.abs() * half.x + m.y_axis.abs() * half.y + m.z_axis.abs() * half.z;
+        Self {
+            min: (center - extent).into(),
+            max: (center + extent).into(),
+        }
+    }
 }
 
 impl<T> super::ObjectBuilder<'_, T> {
-    //TODO: should we accept `V: Into<mint::...>` here?
-    pub fn position(&mut self, position: mint::Vector3<f32>) -> &mut Self {
+    /// Replace the whole local transform.
+    pub fn transform(&mut self, transform: Transform) -> &mut Self {
+        self.node.local = transform;
+        self
+    }
+
+    pub fn position(&mut self, position: impl Into<mint::Vector3<f32>>) -> &mut Self {
         self.node.local.position = position.into();
         self
     }
 
     pub fn scale(&mut self, scale: f32) -> &mut Self {
-        self.node.local.scale = scale;
+        self.node.local.scale = [scale; 3].into();
         self
     }
 
-    pub fn orientation_around(&mut self, axis: mint::Vector3<f32>, angle_deg: f32) -> &mut Self {
+    pub fn non_uniform_scale(&mut self, scale: impl Into<mint::Vector3<f32>>) -> &mut Self {
+        self.node.local.scale = scale.into();
+        self
+    }
+
+    pub fn orientation_around(
+        &mut self,
+        axis: impl Into<mint::Vector3<f32>>,
+        angle_deg: f32,
+    ) -> &mut Self {
         self.node.local.orientation =
-            glam::Quat::from_axis_angle(axis.into(), angle_deg.to_radians());
+            glam::Quat::from_axis_angle(glam::Vec3::from(axis.into()), angle_deg.to_radians())
+                .into();
         self
     }
 
-    pub fn orientation(&mut self, quat: mint::Quaternion<f32>) -> &mut Self {
+    pub fn orientation(&mut self, quat: impl Into<mint::Quaternion<f32>>) -> &mut Self {
         self.node.local.orientation = quat.into();
         self
     }
 
-    pub fn look_at(&mut self, target: mint::Vector3<f32>, up: mint::Vector3<f32>) -> &mut Self {
-        /* // This path just doesn't work well
-        let dir = (glam::Vec3::from(target) - self.node.local.position).normalize();
-        self.node.local.orientation = glam::Quat::from_rotation_arc(-glam::Vec3::Z, dir);
-            * glam::Quat::from_rotation_arc(glam::Vec3::Y, up.into());
-        let temp = glam::Quat::from_rotation_arc(glam::Vec3::Y, up.into();
-        let new_dir = temp * -glam::Vec3::Z;
-        self.node.local.orientation = glam::Quat::from_rotation_arc(-glam::Vec3::Z, dir);
-        */
-
-        let affine = glam::Affine3A::look_at_rh(self.node.local.position, target.into(), up.into());
-        let (_, rot, _) = affine.inverse().to_scale_rotation_translation();
-        // translation here is expected to match `self.node.local.position`
-        self.node.local.orientation = rot;
-
-        /* // Blocked on https://github.com/bitshifter/glam-rs/issues/235
-        let dir = self.node.local.position - glam::Vec3::from(target);
-        let f = dir.normalize();
-        let s = glam::Vec3::from(up).cross(f).normalize();
-        let u = f.cross(s);
-        self.node.local.orientation = glam::Quat::from_rotation_axes(s, u, f);
-        */
+    /// Rotate by `angle` radians around the `axis`, on top of the current orientation.
+    ///
+    /// Consecutive calls compose, each rotation being applied after the previous
+    /// ones in the parent space. A zero-length axis doesn't rotate.
+    pub fn rotation(&mut self, axis: impl Into<mint::Vector3<f32>>, angle: f32) -> &mut Self {
+        let axis = glam::Vec3::from(axis.into()).normalize_or_zero();
+        if axis != glam::Vec3::ZERO {
+            let rotation = glam::Quat::from_axis_angle(axis, angle);
+            self.node.local.orientation = (rotation * self.node.local.glam_orientation()).into();
+        }
+        self
+    }
+
+    /// Same as [`Self::rotation`], but with the angle in degrees.
+    pub fn rotation_deg(&mut self, axis: impl Into<mint::Vector3<f32>>, angle: f32) -> &mut Self {
+        self.rotation(axis, angle.to_radians())
+    }
+
+    /// Set the orientation from Euler angles in radians, see [`Transform::from_euler`].
+    pub fn rotation_euler(&mut self, yaw: f32, pitch: f32, roll: f32) -> &mut Self {
+        self.node.local.orientation = Transform::from_euler(yaw, pitch, roll).orientation;
+        self
+    }
+
+    /// Same as [`Self::rotation_euler`], but with angles in degrees.
+    pub fn rotation_euler_deg(&mut self, yaw: f32, pitch: f32, roll: f32) -> &mut Self {
+        self.node.local.orientation = Transform::from_euler_deg(yaw, pitch, roll).orientation;
+        self
+    }
+
+    /// Orient towards the `target`, as seen from the current position.
+    ///
+    /// See [`Transform::look_at`] for the conventions.
+    pub fn look_at(
+        &mut self,
+        target: impl Into<mint::Vector3<f32>>,
+        up: impl Into<mint::Vector3<f32>>,
+    ) -> &mut Self {
+        self.node.local.orientation =
+            Transform::look_at(self.node.local.position, target.into(), up.into()).orientation;
         self
     }
 }
 
 impl super::Node {
+    pub fn transform(&self) -> Transform {
+        self.local
+    }
+    pub fn set_transform(&mut self, transform: Transform) {
+        self.local = transform;
+    }
+
     pub fn get_position(&self) -> mint::Vector3<f32> {
-        self.local.position.into()
+        self.local.position
     }
     pub fn set_position(&mut self, pos: mint::Vector3<f32>) {
-        self.local.position = pos.into();
+        self.local.position = pos;
     }
     pub fn pre_move(&mut self, offset: mint::Vector3<f32>) {
-        let other = Space {
-            position: offset.into(),
-            scale: 1.0,
-            orientation: glam::Quat::IDENTITY,
-        };
-        self.local = other.combine(&self.local);
+        let other = Transform::from_position(offset);
+        self.local = other * self.local;
     }
     pub fn post_move(&mut self, offset: mint::Vector3<f32>) {
-        self.local.position += glam::Vec3::from(offset);
+        self.local.position = (self.local.glam_position() + glam::Vec3::from(offset)).into();
     }
 
     pub fn get_rotation(&self) -> (mint::Vector3<f32>, f32) {
-        let (axis, angle) = self.local.orientation.to_axis_angle();
+        let (axis, angle) = self.local.glam_orientation().to_axis_angle();
         (axis.into(), angle.to_degrees())
     }
     pub fn set_rotation(&mut self, axis: mint::Vector3<f32>, angle_deg: f32) {
-        self.local.orientation = glam::Quat::from_axis_angle(axis.into(), angle_deg.to_radians());
+        self.local.orientation =
+            glam::Quat::from_axis_angle(axis.into(), angle_deg.to_radians()).into();
     }
     pub fn pre_rotate(&mut self, axis: mint::Vector3<f32>, angle_deg: f32) {
-        self.local.orientation = self.local.orientation
-            * glam::Quat::from_axis_angle(axis.into(), angle_deg.to_radians());
+        let rotation = glam::Quat::from_axis_angle(axis.into(), angle_deg.to_radians());
+        self.local.orientation = (self.local.glam_orientation() * rotation).into();
     }
     pub fn post_rotate(&mut self, axis: mint::Vector3<f32>, angle_deg: f32) {
-        let other = Space {
-            position: glam::Vec3::ZERO,
-            scale: 1.0,
-            orientation: glam::Quat::from_axis_angle(axis.into(), angle_deg.to_radians()),
-        };
-        self.local = other.combine(&self.local);
+        let other = Transform::from_orientation(mint::Quaternion::from(
+            glam::Quat::from_axis_angle(axis.into(), angle_deg.to_radians()),
+        ));
+        self.local = other * self.local;
     }
 
-    pub fn get_scale(&self) -> f32 {
+    pub fn get_scale(&self) -> mint::Vector3<f32> {
         self.local.scale
     }
     pub fn set_scale(&mut self, scale: f32) {
+        self.local.scale = [scale; 3].into();
+    }
+    pub fn set_non_uniform_scale(&mut self, scale: mint::Vector3<f32>) {
         self.local.scale = scale;
     }
 }
 
 #[derive(Debug)]
 pub struct RawSpace {
+    /// Position in XYZ, and the largest absolute scale factor in W,
+    /// which is handy for scaling bounding spheres.
     pub pos_scale: [f32; 4],
     pub rot: [f32; 4],
+    /// Per-axis scale in XYZ, W is unused.
+    pub scale: [f32; 4],
 }
 
-impl From<Space> for RawSpace {
-    fn from(s: Space) -> Self {
+impl From<Transform> for RawSpace {
+    fn from(t: Transform) -> Self {
+        let max_scale = t.scale.x.abs().max(t.scale.y.abs()).max(t.scale.z.abs());
         Self {
-            pos_scale: [s.position.x, s.position.y, s.position.z, s.scale],
-            rot: s.orientation.into(),
+            pos_scale: [t.position.x, t.position.y, t.position.z, max_scale],
+            rot: t.orientation.into(),
+            scale: [t.scale.x, t.scale.y, t.scale.z, 0.0],
         }
     }
 }
 
 impl RawSpace {
-    pub(super) fn to_space(&self) -> Space {
-        Space {
-            position: glam::Vec3::new(self.pos_scale[0], self.pos_scale[1], self.pos_scale[2]),
-            scale: self.pos_scale[3],
-            orientation: glam::Quat::from_array(self.rot),
+    pub(super) fn to_transform(&self) -> Transform {
+        Transform {
+            position: [self.pos_scale[0], self.pos_scale[1], self.pos_scale[2]].into(),
+            scale: [self.scale[0], self.scale[1], self.scale[2]].into(),
+            orientation: self.rot.into(),
         }
     }
 
     pub fn inverse_matrix(&self) -> mint::ColumnMatrix4<f32> {
-        self.to_space().inverse().to_matrix().into()
+        self.to_transform().glam_matrix().inverse().into()
     }
 }
 
@@ -180,6 +568,14 @@ pub enum Projection {
         /// The width is derived from the height based on the current aspect ratio.
         extent_y: f32,
     },
+    /// Orthographic projection of a fixed rectangle in view space,
+    /// stretched over the target regardless of its aspect ratio.
+    OrthographicBounds {
+        left: f32,
+        right: f32,
+        bottom: f32,
+        top: f32,
+    },
     Perspective {
         /// Vertical field of view, in degrees.
         /// Note: the horizontal FOV is computed based on the aspect.
@@ -194,7 +590,10 @@ pub struct Camera {
     /// `depth.start` maps to 0.0, and `depth.end` maps to 1.0.
     pub depth: ops::Range<f32>,
     pub node: super::NodeRef,
-    pub background: super::Color,
+    /// Color or gradient of the background, where the skybox isn't drawn.
+    pub background: super::Background,
+    /// Cube map to draw as the background, by the passes that support it.
+    pub skybox: Option<super::Skybox>,
 }
 
 impl Default for Camera {
@@ -206,12 +605,45 @@ impl Default for Camera {
             },
             depth: 0.0..1.0,
             node: super::NodeRef::default(),
-            background: super::Color::default(),
+            background: super::Background::default(),
+            skybox: None,
         }
     }
 }
 
 impl Camera {
+    /// Orthographic camera showing exactly the `left..right` and `bottom..top`
+    /// ranges, and the `near..far` range of depth in front of the camera node.
+    ///
+    /// The bounds don't follow the aspect ratio of the target, see
+    /// [`Projection::Orthographic`] for a projection that does.
+    pub fn orthographic(left: f32, right: f32, bottom: f32, top: f32, near: f32, far: f32) -> Self {
+        Self {
+            projection: Projection::OrthographicBounds {
+                left,
+                right,
+                bottom,
+                top,
+            },
+            depth: near..far,
+            ..Self::default()
+        }
+    }
+
+    /// Color to clear the target with, see [`Background::clear_color`](super::Background::clear_color).
+    pub fn clear_color(&self) -> super::Color {
+        self.background.clear_color()
+    }
+
+    /// World-to-view matrix, the inverse of the world transform of the camera node.
+    pub fn view_matrix(&self, scene: &super::Scene) -> mint::ColumnMatrix4<f32> {
+        scene
+            .world_transform(self.node)
+            .glam_matrix()
+            .inverse()
+            .into()
+    }
+
     pub fn projection_matrix(&self, aspect: f32) -> mint::ColumnMatrix4<f32> {
         let matrix = match self.projection {
             Projection::Orthographic { center, extent_y } => {
@@ -225,6 +657,19 @@ impl Camera {
                     self.depth.end,
                 )
             }
+            Projection::OrthographicBounds {
+                left,
+                right,
+                bottom,
+                top,
+            } => glam::Mat4::orthographic_rh(
+                left,
+                right,
+                bottom,
+                top,
+                self.depth.start,
+                self.depth.end,
+            ),
             Projection::Perspective { fov_y } => {
                 let fov = fov_y.to_radians();
                 if self.depth.end == f32::INFINITY {
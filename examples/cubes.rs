@@ -138,7 +138,7 @@ fn main() {
     };
 
     let window = Window::new().title("Cubeception").build();
-    let mut context = pollster::block_on(baryon::Context::init().build(&window));
+    let mut context = pollster::block_on(baryon::Context::init().build(&window)).unwrap();
     let mut scene = baryon::Scene::new();
 
     let camera = baryon::Camera {
@@ -146,10 +146,11 @@ fn main() {
         depth: 1.0..10.0,
         node: scene
             .add_node()
-            .position([1.8f32, -8.0, 3.0].into())
-            .look_at([0f32; 3].into(), [0f32, 0.0, 1.0].into())
+            .position([1.8f32, -8.0, 3.0])
+            .look_at([0f32; 3], [0f32, 0.0, 1.0])
             .build(),
-        background: baryon::Color(0xFF203040),
+        background: baryon::Color(0xFF203040).into(),
+        skybox: None,
     };
 
     let prototype = Geometry::cuboid(
@@ -167,6 +168,7 @@ fn main() {
     let mut pass = baryon::pass::Solid::new(
         &baryon::pass::SolidConfig {
             cull_back_faces: true,
+            wireframe: false,
         },
         &context,
     );
@@ -192,7 +194,7 @@ fn main() {
                 );
             }
 
-            context.present(&mut pass, &scene, &camera);
+            context.present(&mut pass, &scene, &camera).unwrap();
         }
         _ => {}
     })
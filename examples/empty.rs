@@ -22,7 +22,7 @@ impl baryon::Pass for Clear {
                     view: &target.view,
                     resolve_target: None,
                     ops: wgpu::Operations {
-                        load: wgpu::LoadOp::Clear(camera.background.into()),
+                        load: wgpu::LoadOp::Clear(camera.clear_color().into()),
                         store: true,
                     },
                 }],
@@ -38,10 +38,12 @@ fn main() {
     use baryon::window::{Event, Window};
 
     let window = Window::new().title("Empty").build();
-    let mut context = pollster::block_on(baryon::Context::init().build(&window));
+    let mut context = pollster::block_on(baryon::Context::init().build(&window)).unwrap();
     let scene = baryon::Scene::new();
-    let mut camera = baryon::Camera::default();
-    camera.background = baryon::Color(0xFF203040);
+    let camera = baryon::Camera {
+        background: baryon::Color(0xFF203040).into(),
+        ..Default::default()
+    };
     let mut pass = Clear;
 
     window.run(move |event| match event {
@@ -49,7 +51,7 @@ fn main() {
             context.resize(width, height);
         }
         Event::Draw => {
-            context.present(&mut pass, &scene, &camera);
+            context.present(&mut pass, &scene, &camera).unwrap();
         }
         _ => {}
     })
@@ -2,7 +2,7 @@ fn main() {
     use baryon::window::{Event, Window};
 
     let window = Window::new().title("Load GLTF").build();
-    let mut context = pollster::block_on(baryon::Context::init().build(&window));
+    let mut context = pollster::block_on(baryon::Context::init().build(&window)).unwrap();
     let mut scene = baryon::Scene::new();
 
     let node = scene.add_node().build();
@@ -31,7 +31,7 @@ fn main() {
         }
         Event::Draw => {
             let camera = module.cameras.find("Camera").unwrap();
-            context.present(&mut pass, &scene, camera);
+            context.present(&mut pass, &scene, camera).unwrap();
         }
         _ => {}
     })
@@ -2,7 +2,7 @@ fn main() {
     use baryon::window::{Event, Window};
 
     let window = Window::new().title("Load OBJ").build();
-    let mut context = pollster::block_on(baryon::Context::init().build(&window));
+    let mut context = pollster::block_on(baryon::Context::init().build(&window)).unwrap();
     let mut scene = baryon::Scene::new();
 
     let camera = baryon::Camera {
@@ -10,10 +10,11 @@ fn main() {
         depth: 1.0..10.0,
         node: scene
             .add_node()
-            .position([-3f32, 2.0, 5.0].into())
-            .look_at([1.0, 0.0, 0.0].into(), [0f32, 1.0, 0.0].into())
+            .position([-3f32, 2.0, 5.0])
+            .look_at([1.0, 0.0, 0.0], [0f32, 1.0, 0.0])
             .build(),
-        background: baryon::Color(0xFF203040),
+        background: baryon::Color(0xFF203040).into(),
+        skybox: None,
     };
 
     let _entities = baryon::asset::load_obj(
@@ -26,6 +27,7 @@ fn main() {
     let mut pass = baryon::pass::Solid::new(
         &baryon::pass::SolidConfig {
             cull_back_faces: true,
+            wireframe: false,
         },
         &context,
     );
@@ -35,7 +37,7 @@ fn main() {
             context.resize(width, height);
         }
         Event::Draw => {
-            context.present(&mut pass, &scene, &camera);
+            context.present(&mut pass, &scene, &camera).unwrap();
         }
         _ => {}
     })
@@ -5,7 +5,7 @@ fn main() {
     };
 
     let window = Window::new().title("Phong").build();
-    let mut context = pollster::block_on(baryon::Context::init().build(&window));
+    let mut context = pollster::block_on(baryon::Context::init().build(&window)).unwrap();
     let mut scene = baryon::Scene::new();
 
     let camera = baryon::Camera {
@@ -13,20 +13,23 @@ fn main() {
         depth: 1.0..10.0,
         node: scene
             .add_node()
-            .position([-1.8f32, 5.0, 2.0].into())
-            .look_at([0f32; 3].into(), [0f32, 0.0, 1.0].into())
+            .position([-1.8f32, 5.0, 2.0])
+            .look_at([0f32; 3], [0f32, 0.0, 1.0])
             .build(),
-        background: baryon::Color(0xFF203040),
+        background: baryon::Color(0xFF203040).into(),
+        skybox: None,
     };
 
     let _point_light = scene
         .add_point_light()
-        .position([3.0, 3.0, 3.0].into())
+        .position([3.0, 3.0, 3.0])
+        .intensity(20.0)
+        .range(20.0)
         .color(baryon::Color(0xFFFF8080))
         .build();
     let _dir_light = scene
         .add_directional_light()
-        .position([0.0, 0.0, 5.0].into())
+        .position([0.0, 0.0, 5.0])
         .intensity(3.0)
         .color(baryon::Color(0xFF8080FF))
         .build();
@@ -35,19 +38,19 @@ fn main() {
 
     let _m_flat = scene
         .add_entity(&prototype)
-        .position([-2.5, 0.0, 0.0].into())
+        .position([-2.5, 0.0, 0.0])
         .component(baryon::Color(0xFF808080))
         .component(baryon::pass::Shader::Gouraud { flat: true })
         .build();
     let _m_gouraud = scene
         .add_entity(&prototype)
-        .position([0.0, 0.0, 0.0].into())
+        .position([0.0, 0.0, 0.0])
         .component(baryon::Color(0xFF808080))
         .component(baryon::pass::Shader::Gouraud { flat: false })
         .build();
     let _m_phong = scene
         .add_entity(&prototype)
-        .position([2.5, 0.0, 0.0].into())
+        .position([2.5, 0.0, 0.0])
         .component(baryon::Color(0xFF808080))
         .component(baryon::pass::Shader::Phong { glossiness: 10 })
         .build();
@@ -69,7 +72,7 @@ fn main() {
             context.resize(width, height);
         }
         Event::Draw => {
-            context.present(&mut pass, &scene, &camera);
+            context.present(&mut pass, &scene, &camera).unwrap();
         }
         _ => {}
     })
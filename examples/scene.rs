@@ -1,5 +1,3 @@
-use mint::Vector3;
-
 fn main() {
     use baryon::{
         geometry::{Geometry, Streams},
@@ -9,7 +7,7 @@ fn main() {
     };
 
     let window = Window::new().title("Scene").build();
-    let mut context = pollster::block_on(Context::init().build(&window));
+    let mut context = pollster::block_on(Context::init().build(&window)).unwrap();
     let mut scene = Scene::new();
 
     let camera = Camera {
@@ -17,26 +15,28 @@ fn main() {
         depth: 1.0..10.0,
         node: scene
             .add_node()
-            .position([-2.0, 2.5, 5.0].into())
-            .look_at([0f32; 3].into(), [0f32, 1.0, 0.0].into())
+            .position([-2.0, 2.5, 5.0])
+            .look_at([0f32; 3], [0f32, 1.0, 0.0])
             .build(),
-        background: Color::BLACK_OPAQUE,
+        background: Color::BLACK_OPAQUE.into(),
+        skybox: None,
     };
 
     scene
         .add_point_light()
-        .position([4.0, 8.0, 4.0].into())
+        .position([4.0, 8.0, 4.0])
+        .intensity(100.0)
         .color(Color(0x00AAAAAA))
         .build();
     scene
         .add_entity(&Geometry::plane(5.0).bake(&mut context))
-        .position([0.0, 0.0, 0.0].into())
+        .position([0.0, 0.0, 0.0])
         .component(Color(0xFF006400))
         .component(Shader::Phong { glossiness: 100 })
         .build();
     scene
         .add_entity(&Geometry::cuboid(Streams::NORMAL, [0.5, 0.5, 0.5].into()).bake(&mut context))
-        .position([0.0, 0.25, 0.0].into())
+        .position([0.0, 0.25, 0.0])
         .component(Color::new(0.8, 0.7, 0.6, 1.0))
         .component(Shader::Phong { glossiness: 100 })
         .build();
@@ -51,7 +51,7 @@ fn main() {
             context.resize(width, height);
         }
         Event::Draw => {
-            context.present(&mut pass, &scene, &camera);
+            context.present(&mut pass, &scene, &camera).unwrap();
         }
         _ => {}
     })
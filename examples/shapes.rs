@@ -10,7 +10,7 @@ use lyon::{algorithms::aabb::bounding_rect, math::point, path::Path, tessellatio
 
 fn main() {
     let window = Window::new().title("Shapes").build();
-    let mut context = pollster::block_on(baryon::Context::init().build(&window));
+    let mut context = pollster::block_on(baryon::Context::init().build(&window)).unwrap();
     let mut scene = baryon::Scene::new();
 
     // Build a Path.
@@ -46,15 +46,17 @@ fn main() {
         depth: 1.0..100.0,
         node: scene
             .add_node()
-            .position([0.0f32, 0.0, -30.0].into())
-            .look_at([0f32; 3].into(), [0f32, -1.0, 0.0].into())
+            .position([0.0f32, 0.0, -30.0])
+            .look_at([0f32; 3], [0f32, -1.0, 0.0])
             .build(),
-        background: baryon::Color::BLACK_OPAQUE,
+        background: baryon::Color::BLACK_OPAQUE.into(),
+        skybox: None,
     };
 
     let mut pass = pass::Solid::new(
         &pass::SolidConfig {
             cull_back_faces: false,
+            wireframe: false,
         },
         &context,
     );
@@ -64,7 +66,7 @@ fn main() {
             context.resize(width, height);
         }
         Event::Draw => {
-            context.present(&mut pass, &scene, &camera);
+            context.present(&mut pass, &scene, &camera).unwrap();
         }
         _ => {}
     })
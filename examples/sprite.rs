@@ -11,8 +11,9 @@ struct Animator {
 }
 
 #[repr(usize)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
 enum State {
+    #[default]
     Idle = 0,
     MoveRight = 9,
     MoveLeft = 8,
@@ -20,20 +21,11 @@ enum State {
     Jump = 10,
     Lie = 12,
 }
-impl Default for State {
-    fn default() -> Self {
-        Self::Idle
-    }
-}
 
 impl Animator {
     fn update_uv(&mut self, scene: &mut baryon::Scene) {
         let uv_range = self.map.at(self.current);
-        scene
-            .world
-            .get_mut::<baryon::Sprite>(self.sprite)
-            .unwrap()
-            .uv = Some(uv_range);
+        scene.get_mut::<baryon::Sprite>(self.sprite).unwrap().uv = Some(uv_range);
     }
 
     fn switch(&mut self, state: State, scene: &mut baryon::Scene) {
@@ -66,7 +58,7 @@ fn main() {
     use baryon::window::{Event, Key, Window};
 
     let window = Window::new().title("Sprite").build();
-    let mut context = pollster::block_on(baryon::Context::init().build(&window));
+    let mut context = pollster::block_on(baryon::Context::init().build(&window)).unwrap();
     let mut scene = baryon::Scene::new();
     let camera = baryon::Camera {
         projection: baryon::Projection::Orthographic {
@@ -118,7 +110,7 @@ fn main() {
         }
         Event::Draw => {
             anim.tick(&mut scene);
-            context.present(&mut pass, &scene, &camera);
+            context.present(&mut pass, &scene, &camera).unwrap();
         }
         _ => {}
     })
@@ -77,6 +77,11 @@ fn load_primitive<'a>(
 ) -> Primitive {
     let reader = primitive.reader(|buffer| Some(&buffers[buffer.index()].0));
     let mut mesh_builder = context.add_mesh();
+    let bounding_box = primitive.bounding_box();
+    mesh_builder.bounds(crate::Aabb {
+        min: bounding_box.min.into(),
+        max: bounding_box.max.into(),
+    });
 
     if let Some(indices) = reader.read_indices() {
         scratch.indices.clear();
@@ -178,7 +183,7 @@ pub fn load_gltf(
     let (gltf, buffers, images) = gltf::import(path).expect("invalid glTF 2.0");
 
     let mut textures = Vec::with_capacity(images.len());
-    for (_texture, data) in gltf.textures().zip(images.into_iter()) {
+    for (_texture, data) in gltf.textures().zip(images) {
         let texture = load_texture(data, context);
         textures.push(texture);
     }
@@ -212,24 +217,16 @@ pub fn load_gltf(
         log::debug!("Node {:?}", gltf_node.name());
 
         let (translation, rotation, scale) = gltf_node.transform().decomposed();
-        let uniform_scale = if scale[1] != scale[0] || scale[2] != scale[0] {
-            log::warn!(
-                "Node[{}] scale {:?} is non-uniform",
-                gltf_node.index(),
-                scale
-            );
-            (scale[0] + scale[1] + scale[2]) / 3.0
-        } else {
-            scale[0]
-        };
-
-        let node = scene
-            .add_node()
+        let mut builder = scene.add_node();
+        builder
             .parent(parent)
-            .position(translation.into())
-            .orientation(rotation.into())
-            .scale(uniform_scale)
-            .build();
+            .position(translation)
+            .orientation(rotation)
+            .non_uniform_scale(scale);
+        if let Some(name) = gltf_node.name() {
+            builder.name(name);
+        }
+        let node = builder.build();
 
         for gltf_child in gltf_node.children() {
             deque.push_back(PreNode {
@@ -284,7 +281,8 @@ pub fn load_gltf(
                     projection,
                     depth,
                     node,
-                    background: bc::Color::default(),
+                    background: bc::Background::default(),
+                    skybox: None,
                 },
                 name: gltf_camera.name().map(str::to_string),
             });
@@ -6,7 +6,7 @@ mod obj;
 #[cfg(feature = "gltf")]
 pub use self::gltf::load_gltf;
 #[cfg(feature = "obj")]
-pub use self::obj::load_obj;
+pub use self::obj::{load_obj, load_obj_mesh, ObjMeshError};
 
 /// A common ancestor of "sprite sheet", "tile map".
 pub struct SpriteMap {
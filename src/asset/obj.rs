@@ -1,4 +1,83 @@
-use std::{iter, path::Path};
+use std::{fmt, io, iter, path::Path};
+
+/// Failure to load a mesh with [`load_obj_mesh`].
+#[derive(Debug)]
+pub enum ObjMeshError {
+    /// The file couldn't be read, for example because it doesn't exist.
+    Io(io::Error),
+    /// The file isn't valid Wavefront Obj.
+    Parse(obj::ObjError),
+}
+
+impl fmt::Display for ObjMeshError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Self::Io(ref e) => write!(f, "unable to read the file: {}", e),
+            Self::Parse(ref e) => write!(f, "invalid Obj data: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for ObjMeshError {}
+
+/// Read and parse the file, telling I/O failures apart from bad data.
+fn read_obj(path: impl AsRef<Path>) -> Result<obj::Obj, ObjMeshError> {
+    obj::Obj::load(path).map_err(|e| match e {
+        obj::ObjError::Io(e) => ObjMeshError::Io(e),
+        other => ObjMeshError::Parse(other),
+    })
+}
+
+/// Load all the groups of a Wavefront Obj file as a single mesh.
+///
+/// Polygons are triangulated as fans. Normals and texture coordinates
+/// are only included if every vertex has them.
+pub fn load_obj_mesh(
+    path: impl AsRef<Path>,
+    context: &mut crate::Context,
+) -> Result<crate::Prototype, ObjMeshError> {
+    let obj = read_obj(path)?;
+
+    let mut positions = Vec::new();
+    let mut normals = Vec::new();
+    let mut tex_coords = Vec::new();
+    let mut vertex_count = 0;
+    for object in obj.data.objects.iter() {
+        for group in object.groups.iter() {
+            for poly in group.polys.iter() {
+                for i in 1..poly.0.len().saturating_sub(1) {
+                    for &elem_index in [0, i, i + 1].iter() {
+                        let obj::IndexTuple(pos_id, tex_id, nor_id) = poly.0[elem_index];
+                        vertex_count += 1;
+                        positions.push(crate::Position(obj.data.position[pos_id]));
+                        if let Some(index) = nor_id {
+                            normals.push(crate::Normal(obj.data.normal[index]));
+                        }
+                        if let Some(index) = tex_id {
+                            // Obj has the origin of V at the bottom
+                            let [u, v] = obj.data.texture[index];
+                            let to_u16 = |c: f32| (c.clamp(0.0, 1.0) * 65535.0) as u16;
+                            tex_coords.push(crate::TexCoords([to_u16(u), to_u16(1.0 - v)]));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    let mut mesh_builder = context.add_mesh();
+    mesh_builder.vertex(&positions);
+    if let Some(bounds) = crate::Aabb::from_points(positions.iter().map(|p| p.0)) {
+        mesh_builder.bounds(bounds);
+    }
+    if normals.len() == vertex_count {
+        mesh_builder.vertex(&normals);
+    }
+    if tex_coords.len() == vertex_count {
+        mesh_builder.vertex(&tex_coords);
+    }
+    Ok(mesh_builder.build())
+}
 
 /// Load entities from Wavefront Obj format.
 pub fn load_obj(
@@ -42,6 +121,9 @@ pub fn load_obj(
 
             let mut mesh_builder = context.add_mesh();
             mesh_builder.vertex(&positions);
+            if let Some(bounds) = crate::Aabb::from_points(positions.iter().map(|p| p.0)) {
+                mesh_builder.bounds(bounds);
+            }
             if !normals.is_empty() {
                 mesh_builder.vertex(&normals);
             }
@@ -57,9 +139,9 @@ pub fn load_obj(
             );
             if let Some(obj::ObjMaterial::Mtl(ref mat)) = group.material {
                 if let Some(cf) = mat.kd {
-                    let color = cf.iter().fold(0xFF, |u, c| {
-                        (u << 8) + (c * 255.0).max(0.0).min(255.0) as u32
-                    });
+                    let color = cf
+                        .iter()
+                        .fold(0xFF, |u, c| (u << 8) + (c * 255.0).clamp(0.0, 255.0) as u32);
                     entity_builder.component(crate::Color(color));
                 }
                 if !normals.is_empty() {
@@ -79,3 +161,28 @@ pub fn load_obj(
 
     entities
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{read_obj, ObjMeshError};
+
+    #[test]
+    fn read_errors() {
+        match read_obj("missing.obj") {
+            Err(ObjMeshError::Io(e)) => assert_eq!(e.kind(), std::io::ErrorKind::NotFound),
+            other => panic!("Unexpected {:?}", other.map(|_| ())),
+        }
+
+        let path = std::env::temp_dir().join("baryon-invalid.obj");
+        std::fs::write(&path, "v one two three\n").unwrap();
+        let result = read_obj(&path);
+        std::fs::remove_file(&path).unwrap();
+        match result {
+            Err(ObjMeshError::Parse(_)) => {}
+            other => panic!("Unexpected {:?}", other.map(|_| ())),
+        }
+
+        let path = format!("{}/examples/assets/car.obj", env!("CARGO_MANIFEST_DIR"));
+        assert!(read_obj(&path).is_ok());
+    }
+}
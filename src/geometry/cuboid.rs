@@ -1,6 +1,10 @@
 use std::iter;
 
 impl super::Geometry {
+    pub fn cube(streams: super::Streams, size: f32) -> Self {
+        Self::cuboid(streams, [size / 2.0; 3].into())
+    }
+
     pub fn cuboid(streams: super::Streams, half_extent: mint::Vector3<f32>) -> Self {
         let pos = |x, y, z| {
             crate::Position([
@@ -3,6 +3,7 @@ pub mod cuboid;
 pub mod shape;
 pub mod sphere;
 pub mod plane;
+pub mod quad;
 
 bitflags::bitflags!(
     /// Types of optional vertex streams.
@@ -19,9 +20,17 @@ pub struct Geometry {
 }
 
 impl Geometry {
+    /// Bounding box of the positions, or `None` if there are none.
+    pub fn bounds(&self) -> Option<bc::Aabb> {
+        bc::Aabb::from_points(self.positions.iter().map(|p| p.0))
+    }
+
     pub fn bake(&self, context: &mut bc::Context) -> bc::Prototype {
         let mut mb = context.add_mesh();
         mb.radius(self.radius);
+        if let Some(bounds) = self.bounds() {
+            mb.bounds(bounds);
+        }
         mb.vertex(&self.positions);
         if let Some(ref stream) = self.normals {
             mb.vertex(stream);
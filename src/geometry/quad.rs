@@ -0,0 +1,29 @@
+use crate::{Normal, Position};
+
+impl super::Geometry {
+    /// Rectangle in the XY plane, facing +Z.
+    pub fn quad(streams: super::Streams, width: f32, height: f32) -> Self {
+        let (x, y) = (width / 2.0, height / 2.0);
+        let radius = (x * x + y * y).sqrt();
+
+        let positions = vec![
+            Position([-x, -y, 0.0]),
+            Position([x, -y, 0.0]),
+            Position([x, y, 0.0]),
+            Position([-x, y, 0.0]),
+        ];
+        let normals = if streams.contains(super::Streams::NORMAL) {
+            Some(vec![Normal([0.0, 0.0, 1.0]); 4])
+        } else {
+            None
+        };
+        let indices = vec![0, 1, 2, 2, 3, 0];
+
+        Self {
+            radius,
+            positions,
+            normals,
+            indices: Some(indices),
+        }
+    }
+}
@@ -86,7 +86,7 @@ impl super::Geometry {
             }
         }
 
-        let indices = faces.into_iter().flat_map(|face| face).collect::<Vec<_>>();
+        let indices = faces.into_iter().flatten().collect::<Vec<_>>();
         let mut positions = Vec::with_capacity(vertices.len());
         let mut normals = if streams.contains(super::Streams::NORMAL) {
             Some(Vec::with_capacity(vertices.len()))
@@ -110,3 +110,63 @@ impl super::Geometry {
         }
     }
 }
+
+impl super::Geometry {
+    /// Sphere made of `rings` bands of latitude, each split into `segments`.
+    ///
+    /// Unlike [`Self::sphere`], the vertices are laid out in a grid,
+    /// with a seam along the +X half of the XY plane.
+    pub fn uv_sphere(streams: super::Streams, radius: f32, segments: usize, rings: usize) -> Self {
+        assert!(segments >= 3 && rings >= 2);
+        let row = segments + 1;
+        assert!(row * (rings + 1) <= u16::MAX as usize);
+
+        let mut positions = Vec::with_capacity(row * (rings + 1));
+        let mut normals = if streams.contains(super::Streams::NORMAL) {
+            Some(Vec::with_capacity(row * (rings + 1)))
+        } else {
+            None
+        };
+        for ring in 0..=rings {
+            let theta = std::f32::consts::PI * ring as f32 / rings as f32;
+            for segment in 0..=segments {
+                let phi = 2.0 * std::f32::consts::PI * segment as f32 / segments as f32;
+                let n = [
+                    theta.sin() * phi.cos(),
+                    theta.cos(),
+                    -theta.sin() * phi.sin(),
+                ];
+                positions.push(crate::Position([
+                    n[0] * radius,
+                    n[1] * radius,
+                    n[2] * radius,
+                ]));
+                if let Some(ref mut normals) = normals {
+                    normals.push(crate::Normal(n));
+                }
+            }
+        }
+
+        let mut indices = Vec::with_capacity(segments * (rings - 1) * 6);
+        for ring in 0..rings {
+            for segment in 0..segments {
+                let a = (ring * row + segment) as u16;
+                let b = a + row as u16;
+                // skip the degenerate triangles at the poles
+                if ring != 0 {
+                    indices.extend_from_slice(&[a, b, a + 1]);
+                }
+                if ring != rings - 1 {
+                    indices.extend_from_slice(&[a + 1, b, b + 1]);
+                }
+            }
+        }
+
+        Self {
+            positions,
+            normals,
+            radius,
+            indices: Some(indices),
+        }
+    }
+}
@@ -1,8 +1,12 @@
+pub use bc::color;
 pub use bc::{
-    Camera, Color, Context, Entity, EntityRef, ImageRef, Light, LightBuilder, LightRef,
-    MeshBuilder, MeshRef, Node, NodeRef, Pass, Projection, Prototype, Scene, Sprite, SpriteBuilder,
-    TargetInfo, TargetRef, UvRange,
+    Aabb, Background, BlendMode, Camera, Color, ColorParseError, ColorSpace, Context, ContextError,
+    Entity, EntityRef, Gradient, ImageRef, Light, LightBuilder, LightRef, Line, LinearColor,
+    MeshBuilder, MeshRef, Node, NodeRef, ParentError, ParentMode, Pass, Projection, Prototype,
+    Scene, Skybox, Sprite, SpriteBuilder, TargetInfo, TargetRef, Transform, UvRange,
 };
+#[cfg(feature = "serde")]
+pub use bc::{SceneLoadError, SceneRegistry};
 use std::mem;
 
 pub mod asset;
@@ -12,6 +12,7 @@ struct Globals {
 struct Locals {
     pos_scale: [f32; 4],
     rot: [f32; 4],
+    scale: [f32; 4],
     // x0,y0, x1,y1
     bounds: [f32; 4],
     // u0,v0, u1,v1
@@ -42,6 +43,7 @@ pub struct Flat {
     uniform_pool: super::BufferPool,
     pipelines: Pipelines,
     temp: Vec<Instance>,
+    backdrop: super::sky::Backdrop,
 }
 
 impl Flat {
@@ -180,6 +182,7 @@ impl Flat {
             uniform_pool: super::BufferPool::uniform("flat locals", d),
             pipelines,
             temp: Vec::new(),
+            backdrop: super::sky::Backdrop::new(target_info, None, d),
         }
     }
 }
@@ -197,6 +200,9 @@ impl bc::Pass for Flat {
 
         let nodes = scene.bake();
         let cam_node = &nodes[camera.node];
+        let sky = self
+            .backdrop
+            .prepare(camera, cam_node, target.aspect(), context);
         self.uniform_pool.reset();
         let queue = context.queue();
 
@@ -215,7 +221,10 @@ impl bc::Pass for Flat {
         self.uniform_pool.reset();
         let cam_dir = glam::Quat::from_slice(&cam_node.rot) * -glam::Vec3::Z;
 
-        for (_, (sprite,)) in scene.world.query::<(&bc::Sprite,)>().iter() {
+        for (_, (sprite,)) in scene.query::<(&bc::Sprite,)>().iter() {
+            if !nodes.is_visible(sprite.node) {
+                continue;
+            }
             let space = &nodes[sprite.node];
             let cam_vector = glam::Vec3::from_slice(&space.pos_scale)
                 - glam::Vec3::from_slice(&cam_node.pos_scale);
@@ -225,6 +234,7 @@ impl bc::Pass for Flat {
             let locals = Locals {
                 pos_scale: space.pos_scale,
                 rot: space.rot,
+                scale: space.scale,
                 bounds: {
                     let (w, h) = match sprite.uv {
                         Some(ref uv) => (uv.end.x - uv.start.x, uv.end.y - uv.start.y),
@@ -291,14 +301,17 @@ impl bc::Pass for Flat {
                 label: Some("flat"),
                 color_attachments: &[wgpu::RenderPassColorAttachment {
                     view: &target.view,
-                    resolve_target: None,
+                    resolve_target: target.resolve_target.as_ref(),
                     ops: wgpu::Operations {
-                        load: wgpu::LoadOp::Clear(camera.background.into()),
+                        load: wgpu::LoadOp::Clear(camera.clear_color().into()),
                         store: true,
                     },
                 }],
                 depth_stencil_attachment: None,
             });
+            if sky {
+                self.backdrop.draw(&mut pass);
+            }
             pass.set_pipeline(&self.pipelines.transparent);
             pass.set_bind_group(0, &self.global_bind_group, &[]);
 
@@ -12,6 +12,7 @@ var sam: sampler;
 struct Locals {
     pos_scale: vec4<f32>;
     rot: vec4<f32>;
+    scale: vec4<f32>;
     bounds: vec4<f32>;
     tex_coords: vec4<f32>;
 };
@@ -40,7 +41,7 @@ fn main_vs([[builtin(vertex_index)]] index: u32) -> Varyings {
         mix(locals.bounds.xw, locals.bounds.zy, tc),
         0.0
     );
-    let world = locals.pos_scale.w * qrot(locals.rot, pos) + locals.pos_scale.xyz;
+    let world = qrot(locals.rot, locals.scale.xyz * pos) + locals.pos_scale.xyz;
     let clip_pos = globals.view_proj * vec4<f32>(world, 1.0);
 
     let tc_sub = mix(locals.tex_coords.xy, locals.tex_coords.zw, tc);
@@ -1,12 +1,13 @@
 mod flat;
 mod phong;
 mod real;
+mod sky;
 mod solid;
 
 pub use flat::Flat;
-pub use phong::{Ambient, Phong, PhongConfig, Shader};
+pub use phong::{Ambient, Phong, PhongConfig, Shader, LIGHTS_PER_ENTITY};
 pub use real::{Material, Real, RealConfig};
-pub use solid::{Solid, SolidConfig};
+pub use solid::{LineMesh, PointMesh, Solid, SolidConfig, Wireframe};
 
 use std::mem;
 
@@ -70,7 +71,7 @@ impl BufferPool {
     }
 
     //TODO: consider lifting `T` up
-    fn binding<T>(&self, index: usize) -> wgpu::BufferBinding {
+    fn binding<T>(&self, index: usize) -> wgpu::BufferBinding<'_> {
         wgpu::BufferBinding {
             buffer: &self.buffers[index],
             offset: 0,
@@ -107,3 +108,41 @@ impl BufferPool {
         self.last_offset = 0;
     }
 }
+
+/// Depth buffer for targets that don't come with one from the context.
+#[derive(Default)]
+struct DepthFallback {
+    texture: Option<(wgpu::TextureView, wgpu::Extent3d, u32)>,
+}
+
+impl DepthFallback {
+    fn view<'a>(
+        &'a mut self,
+        target: &'a bc::Target,
+        device: &wgpu::Device,
+    ) -> &'a wgpu::TextureView {
+        if let Some(ref view) = target.depth {
+            return view;
+        }
+        let reset = match self.texture {
+            Some((_, size, sample_count)) => {
+                size != target.size || sample_count != target.sample_count
+            }
+            None => true,
+        };
+        if reset {
+            let texture = device.create_texture(&wgpu::TextureDescriptor {
+                label: Some("depth"),
+                dimension: wgpu::TextureDimension::D2,
+                format: bc::Target::DEPTH_FORMAT,
+                size: target.size,
+                sample_count: target.sample_count,
+                mip_level_count: 1,
+                usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+            });
+            let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+            self.texture = Some((view, target.size, target.sample_count));
+        }
+        &self.texture.as_ref().unwrap().0
+    }
+}
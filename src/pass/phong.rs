@@ -8,9 +8,12 @@ pub enum Shader {
     Phong { glossiness: u8 },
 }
 
-const DEPTH_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Depth24Plus;
+const DEPTH_FORMAT: wgpu::TextureFormat = bc::Target::DEPTH_FORMAT;
 const INTENSITY_THRESHOLD: f32 = 0.1;
-const LIGHT_COUNT: usize = 4;
+/// Number of lights shading each entity, the ones affecting it the most are picked.
+///
+/// The total number of lights in the scene is limited by [`PhongConfig::max_lights`].
+pub const LIGHTS_PER_ENTITY: usize = 4;
 
 #[repr(C)]
 #[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
@@ -25,6 +28,7 @@ struct Light {
     pos: [f32; 4],
     rot: [f32; 4],
     color_intensity: [f32; 4],
+    range: [f32; 4],
 }
 
 #[repr(C)]
@@ -32,8 +36,9 @@ struct Light {
 struct Locals {
     pos_scale: [f32; 4],
     rot: [f32; 4],
+    scale: [f32; 4],
     color: [f32; 4],
-    lights: [u32; LIGHT_COUNT],
+    lights: [u32; LIGHTS_PER_ENTITY],
     glossiness: f32,
     _pad: [f32; 3],
 }
@@ -82,7 +87,8 @@ impl Default for PhongConfig {
 }
 
 pub struct Phong {
-    depth_texture: Option<(wgpu::TextureView, wgpu::Extent3d)>,
+    depth: super::DepthFallback,
+    backdrop: super::sky::Backdrop,
     global_uniform_buf: wgpu::Buffer,
     light_buf: wgpu::Buffer,
     light_capacity: usize,
@@ -263,7 +269,8 @@ impl Phong {
         };
 
         Self {
-            depth_texture: None,
+            depth: super::DepthFallback::default(),
+            backdrop: super::sky::Backdrop::new(target_info, Some(bc::Target::DEPTH_FORMAT), d),
             global_uniform_buf,
             light_capacity: config.max_lights,
             light_buf,
@@ -289,25 +296,12 @@ impl bc::Pass for Phong {
         let target = context.get_target(targets[0]);
         let device = context.device();
 
-        let reset_depth = match self.depth_texture {
-            Some((_, size)) => size != target.size,
-            None => true,
-        };
-        if reset_depth {
-            let texture = device.create_texture(&wgpu::TextureDescriptor {
-                label: Some("depth"),
-                dimension: wgpu::TextureDimension::D2,
-                format: DEPTH_FORMAT,
-                size: target.size,
-                sample_count: 1,
-                mip_level_count: 1,
-                usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
-            });
-            let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
-            self.depth_texture = Some((view, target.size));
-        }
+        let depth_view = self.depth.view(target, device);
 
         let nodes = scene.bake();
+        let sky = self
+            .backdrop
+            .prepare(camera, &nodes[camera.node], target.aspect(), context);
         self.uniform_pool.reset();
         let queue = context.queue();
 
@@ -338,11 +332,17 @@ impl bc::Pass for Phong {
                     bc::LightKind::Point => 1.0,
                 };
                 let mut color_intensity = light.color.into_vec4();
-                color_intensity[3] = light.intensity;
+                // hidden lights keep their slots, so that the indices match
+                color_intensity[3] = if nodes.is_visible(light.node) {
+                    light.intensity
+                } else {
+                    0.0
+                };
                 Light {
                     pos,
                     rot: space.rot,
                     color_intensity,
+                    range: [light.range, 0.0, 0.0, 0.0],
                 }
             })
             .collect::<Vec<_>>();
@@ -356,7 +356,6 @@ impl bc::Pass for Phong {
         // pre-create the bind groups so that we don't need to do it on the fly
         let local_bgl = &self.local_bind_group_layout;
         let entity_count = scene
-            .world
             .query::<(&bc::Entity, &bc::Color, &Shader)>()
             .with::<bc::Vertex<crate::Position>>()
             .with::<bc::Vertex<crate::Normal>>()
@@ -388,14 +387,14 @@ impl bc::Pass for Phong {
                 label: Some("phong"),
                 color_attachments: &[wgpu::RenderPassColorAttachment {
                     view: &target.view,
-                    resolve_target: None,
+                    resolve_target: target.resolve_target.as_ref(),
                     ops: wgpu::Operations {
-                        load: wgpu::LoadOp::Clear(camera.background.into()),
+                        load: wgpu::LoadOp::Clear(camera.clear_color().into()),
                         store: true,
                     },
                 }],
                 depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
-                    view: &self.depth_texture.as_ref().unwrap().0,
+                    view: depth_view,
                     depth_ops: Some(wgpu::Operations {
                         load: wgpu::LoadOp::Clear(1.0),
                         store: true,
@@ -403,33 +402,43 @@ impl bc::Pass for Phong {
                     stencil_ops: None,
                 }),
             });
+            if sky {
+                self.backdrop.draw(&mut pass);
+            }
 
             pass.set_bind_group(0, &self.global_bind_group, &[]);
 
             for (_, (entity, &color, &shader)) in scene
-                .world
                 .query::<(&bc::Entity, &bc::Color, &Shader)>()
                 .with::<bc::Vertex<crate::Position>>()
                 .with::<bc::Vertex<crate::Normal>>()
                 .iter()
             {
+                if !nodes.is_visible(entity.node) {
+                    continue;
+                }
                 let space = &nodes[entity.node];
                 let mesh = context.get_mesh(entity.mesh);
                 let entity_radius = mesh.bound_radius * space.pos_scale[3];
 
-                // collect the `LIGHT_COUNT` lights most affecting the entity
+                // collect the `LIGHTS_PER_ENTITY` lights most affecting the entity
                 self.temp_lights.clear();
                 let entity_pos = glam::Vec3::from_slice(&space.pos_scale[..3]);
                 for (index, (_, light)) in scene.lights().enumerate() {
+                    if !nodes.is_visible(light.node) {
+                        continue;
+                    }
                     let light_pos = glam::Vec3::from_slice(&nodes[light.node].pos_scale[..3]);
                     let intensity = match light.kind {
                         bc::LightKind::Point => {
                             let distance = (entity_pos - light_pos).length();
                             if distance <= entity_radius {
                                 light.intensity
+                            } else if distance - entity_radius > light.range {
+                                0.0
                             } else {
                                 let bound_distance = (distance - entity_radius).max(1.0);
-                                light.intensity / bound_distance * bound_distance
+                                light.intensity / (bound_distance * bound_distance)
                             }
                         }
                         bc::LightKind::Directional => light.intensity,
@@ -440,7 +449,7 @@ impl bc::Pass for Phong {
                 }
                 self.temp_lights
                     .sort_by_key(|&(intensity, _)| (1.0 / intensity) as usize);
-                let mut light_indices = [0u32; LIGHT_COUNT];
+                let mut light_indices = [0u32; LIGHTS_PER_ENTITY];
                 for (li, &(_, index)) in light_indices.iter_mut().zip(&self.temp_lights) {
                     *li = index;
                 }
@@ -455,7 +464,8 @@ impl bc::Pass for Phong {
                 let locals = Locals {
                     pos_scale: space.pos_scale,
                     rot: space.rot,
-                    color: color.into_vec4_gamma(),
+                    scale: space.scale,
+                    color: bc::LinearColor::from(color).into(),
                     lights: light_indices,
                     glossiness: match shader {
                         Shader::Phong { glossiness } => glossiness as f32,
@@ -15,6 +15,7 @@ struct Light {
     pos: vec4<f32>;
     rot: vec4<f32>;
     color_intensity: vec4<f32>;
+    range: vec4<f32>;
 };
 [[block]]
 struct LightArray {
@@ -23,10 +24,23 @@ struct LightArray {
 [[group(0), binding(1)]]
 var<storage> lights: LightArray;
 
+// Inverse-square falloff of point lights, fading out towards the range.
+// Keep in sync with `attenuation` in real.wgsl.
+fn attenuation(index: u32, world: vec3<f32>) -> f32 {
+    let light = lights.data[index];
+    if (light.pos.w == 0.0) {
+        return 1.0;
+    }
+    let d = distance(light.pos.xyz, world);
+    let fade = clamp(1.0 - pow(d / light.range.x, 4.0), 0.0, 1.0);
+    return fade * fade / max(d * d, 1.0);
+}
+
 [[block]]
 struct Locals {
     pos_scale: vec4<f32>;
     rot: vec4<f32>;
+    scale: vec4<f32>;
     color: vec4<f32>;
     lights: vec4<u32>;
     glossiness: f32;
@@ -57,8 +71,8 @@ fn compute_half(world: vec3<f32>, normal: vec3<f32>, index: u32) -> vec3<f32> {
 
 [[stage(vertex)]]
 fn vs_phong(in: Vertex) -> PhongVaryings {
-    let world = locals.pos_scale.w * qrot(locals.rot, in.pos) + locals.pos_scale.xyz;
-    let normal = qrot(locals.rot, normalize(in.normal));
+    let world = qrot(locals.rot, locals.scale.xyz * in.pos) + locals.pos_scale.xyz;
+    let normal = normalize(qrot(locals.rot, in.normal / locals.scale.xyz));
 
     var out: PhongVaryings;
     out.position = globals.view_proj * vec4<f32>(world, 1.0);
@@ -83,14 +97,15 @@ fn evaluate(world: vec3<f32>, normal: vec3<f32>, half_vec: vec3<f32>, index: u32
 
     let dir = light.pos.xyz - light.pos.w * world;
     let dot_nl = dot(normal, normalize(dir));
+    let intensity = light.color_intensity.w * attenuation(index, world);
 
-    let kd = light.color_intensity.w * max(0.0, dot_nl);
+    let kd = intensity * max(0.0, dot_nl);
     ev.diffuse = kd * light.color_intensity.xyz;
 
-    if (light.color_intensity.w > 0.01 && dot_nl > 0.0) {
+    if (intensity > 0.01 && dot_nl > 0.0) {
         let ks = dot(normal, normalize(half_vec));
         if (ks > 0.0) {
-            ev.specular = pow(ks, locals.glossiness) * light.color_intensity.xyz;
+            ev.specular = intensity * pow(ks, locals.glossiness) * light.color_intensity.xyz;
         }
     }
 
@@ -116,7 +131,7 @@ fn evaluate_flat(world: vec3<f32>, normal: vec3<f32>, index: u32) -> vec3<f32> {
     let dir = light.pos.xyz - light.pos.w * world;
     let dot_nl = dot(normal, normalize(dir));
 
-    let kd = light.color_intensity.w * max(0.0, dot_nl);
+    let kd = light.color_intensity.w * attenuation(index, world) * max(0.0, dot_nl);
     return kd * light.color_intensity.xyz;
 }
 
@@ -128,8 +143,8 @@ struct FlatVaryings {
 
 [[stage(vertex)]]
 fn vs_flat(in: Vertex) -> FlatVaryings {
-    let world = locals.pos_scale.w * qrot(locals.rot, in.pos) + locals.pos_scale.xyz;
-    let normal = qrot(locals.rot, normalize(in.normal));
+    let world = qrot(locals.rot, locals.scale.xyz * in.pos) + locals.pos_scale.xyz;
+    let normal = normalize(qrot(locals.rot, in.normal / locals.scale.xyz));
     let diffuse = globals.ambient.xyz +
         evaluate_flat(world, normal, locals.lights.x) +
         evaluate_flat(world, normal, locals.lights.y) +
@@ -26,7 +26,7 @@ impl Default for Material {
     }
 }
 
-const DEPTH_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Depth24Plus;
+const DEPTH_FORMAT: wgpu::TextureFormat = bc::Target::DEPTH_FORMAT;
 
 #[repr(C)]
 #[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
@@ -41,6 +41,7 @@ struct Light {
     pos: [f32; 4],
     rot: [f32; 4],
     color_intensity: [f32; 4],
+    range: [f32; 4],
 }
 
 #[repr(C)]
@@ -48,6 +49,7 @@ struct Light {
 struct Locals {
     pos_scale: [f32; 4],
     rot: [f32; 4],
+    scale: [f32; 4],
     base_color_factor: [f32; 4],
     emissive_factor: [f32; 4],
     metallic_roughness_values: [f32; 2],
@@ -89,7 +91,8 @@ struct Instance {
 /// Realistic renderer.
 /// Follows Disney PBR.
 pub struct Real {
-    depth_texture: Option<(wgpu::TextureView, wgpu::Extent3d)>,
+    depth: super::DepthFallback,
+    backdrop: super::sky::Backdrop,
     global_uniform_buf: wgpu::Buffer,
     light_buf: wgpu::Buffer,
     light_capacity: usize,
@@ -287,7 +290,8 @@ impl Real {
         };
 
         Self {
-            depth_texture: None,
+            depth: super::DepthFallback::default(),
+            backdrop: super::sky::Backdrop::new(target_info, Some(bc::Target::DEPTH_FORMAT), d),
             global_uniform_buf,
             light_capacity: config.max_lights,
             light_buf,
@@ -313,26 +317,12 @@ impl bc::Pass for Real {
         let target = context.get_target(targets[0]);
         let device = context.device();
 
-        let reset_depth = match self.depth_texture {
-            Some((_, size)) => size != target.size,
-            None => true,
-        };
-        //TODO: abstract this part away
-        if reset_depth {
-            let texture = device.create_texture(&wgpu::TextureDescriptor {
-                label: Some("depth"),
-                dimension: wgpu::TextureDimension::D2,
-                format: DEPTH_FORMAT,
-                size: target.size,
-                sample_count: 1,
-                mip_level_count: 1,
-                usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
-            });
-            let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
-            self.depth_texture = Some((view, target.size));
-        }
+        let depth_view = self.depth.view(target, device);
 
         let nodes = scene.bake();
+        let sky = self
+            .backdrop
+            .prepare(camera, &nodes[camera.node], target.aspect(), context);
         self.uniform_pool.reset();
         let queue = context.queue();
 
@@ -350,6 +340,7 @@ impl bc::Pass for Real {
 
         let lights = scene
             .lights()
+            .filter(|&(_, light)| nodes.is_visible(light.node))
             .map(|(_, light)| {
                 let space = &nodes[light.node];
                 let mut pos = space.pos_scale;
@@ -363,6 +354,7 @@ impl bc::Pass for Real {
                     pos,
                     rot: space.rot,
                     color_intensity,
+                    range: [light.range, 0.0, 0.0, 0.0],
                 }
             })
             .collect::<Vec<_>>();
@@ -378,18 +370,21 @@ impl bc::Pass for Real {
         self.instances.clear();
 
         for (_, (entity, &color, mat)) in scene
-            .world
             .query::<(&bc::Entity, &bc::Color, &Material)>()
             .with::<bc::Vertex<crate::Position>>()
             .with::<bc::Vertex<crate::TexCoords>>()
             .with::<bc::Vertex<crate::Normal>>()
             .iter()
         {
+            if !nodes.is_visible(entity.node) {
+                continue;
+            }
             let space = &nodes[entity.node];
 
             let locals = Locals {
                 pos_scale: space.pos_scale,
                 rot: space.rot,
+                scale: space.scale,
                 base_color_factor: color.into_vec4(),
                 emissive_factor: mat.emissive_color.into_vec4(),
                 metallic_roughness_values: [mat.metallic_factor, mat.roughness_factor],
@@ -441,14 +436,14 @@ impl bc::Pass for Real {
                 label: Some("real"),
                 color_attachments: &[wgpu::RenderPassColorAttachment {
                     view: &target.view,
-                    resolve_target: None,
+                    resolve_target: target.resolve_target.as_ref(),
                     ops: wgpu::Operations {
-                        load: wgpu::LoadOp::Clear(camera.background.into()),
+                        load: wgpu::LoadOp::Clear(camera.clear_color().into()),
                         store: true,
                     },
                 }],
                 depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
-                    view: &self.depth_texture.as_ref().unwrap().0,
+                    view: depth_view,
                     depth_ops: Some(wgpu::Operations {
                         load: wgpu::LoadOp::Clear(1.0),
                         store: true,
@@ -456,6 +451,9 @@ impl bc::Pass for Real {
                     stencil_ops: None,
                 }),
             });
+            if sky {
+                self.backdrop.draw(&mut pass);
+            }
 
             pass.set_pipeline(&self.pipelines.main);
             pass.set_bind_group(0, &self.global_bind_group, &[]);
@@ -23,6 +23,7 @@ var<uniform> globals: Globals;
 struct Locals {
     pos_scale: vec4<f32>;
     rot: vec4<f32>;
+    scale: vec4<f32>;
     base_color_factor: vec4<f32>;
     emissive_factor: vec4<f32>;
     metallic_roughness_values: vec2<f32>;
@@ -38,8 +39,8 @@ fn qrot(q: vec4<f32>, v: vec3<f32>) -> vec3<f32> {
 
 [[stage(vertex)]]
 fn main_vs(in: Attributes) -> Varyings {
-    let world = locals.pos_scale.w * qrot(locals.rot, in.position) + locals.pos_scale.xyz;
-    let normal = qrot(locals.rot, in.normal);
+    let world = qrot(locals.rot, locals.scale.xyz * in.position) + locals.pos_scale.xyz;
+    let normal = normalize(qrot(locals.rot, in.normal / locals.scale.xyz));
 
     return Varyings(
         globals.view_proj * vec4<f32>(world, 1.0),
@@ -57,6 +58,7 @@ struct Light {
     pos: vec4<f32>;
     rot: vec4<f32>;
     color_intensity: vec4<f32>;
+    range: vec4<f32>;
 };
 [[block]]
 struct LightArray {
@@ -65,6 +67,18 @@ struct LightArray {
 [[group(0), binding(1)]]
 var<storage> lights: LightArray;
 
+// Inverse-square falloff of point lights, fading out towards the range.
+// Keep in sync with `attenuation` in phong.wgsl.
+fn attenuation(index: u32, world: vec3<f32>) -> f32 {
+    let light = lights.data[index];
+    if (light.pos.w == 0.0) {
+        return 1.0;
+    }
+    let d = distance(light.pos.xyz, world);
+    let fade = clamp(1.0 - pow(d / light.range.x, 4.0), 0.0, 1.0);
+    return fade * fade / max(d * d, 1.0);
+}
+
 [[group(0), binding(2)]]
 var sam: sampler;
 
@@ -177,7 +191,8 @@ fn main_fs(in: Varyings) -> [[location(0)]] vec4<f32> {
         let d = ggx(pbr_inputs);
         let diffuse_contrib = (1.0 - f) * lambertian_diffuse(pbr_inputs);
         let spec_contrib = f * g * d / (4.0 * ndotl * ndotv);
-        color = color + ndotl * light.color_intensity.w * light.color_intensity.xyz * (diffuse_contrib + spec_contrib);
+        let intensity = light.color_intensity.w * attenuation(i, in.world_pos);
+        color = color + ndotl * intensity * light.color_intensity.xyz * (diffuse_contrib + spec_contrib);
     }
 
     return vec4<f32>(color, base_color.a);
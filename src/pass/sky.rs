@@ -0,0 +1,251 @@
+use bc::ContextDetail as _;
+use std::mem;
+
+#[repr(C)]
+#[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+struct Globals {
+    inv_view_proj: [[f32; 4]; 4],
+    color_start: [f32; 4],
+    color_end: [f32; 4],
+    params: [f32; 4],
+}
+
+enum Fill {
+    Sky,
+    Gradient,
+}
+
+/// Skybox or gradient drawing at the start of a render pass, shared by the passes.
+pub struct Backdrop {
+    uniform_buf: wgpu::Buffer,
+    sampler: wgpu::Sampler,
+    bind_group_layout: wgpu::BindGroupLayout,
+    bind_group: Option<(bc::ImageRef, wgpu::BindGroup)>,
+    pipeline: wgpu::RenderPipeline,
+    gradient_bind_group: wgpu::BindGroup,
+    gradient_pipeline: wgpu::RenderPipeline,
+    fill: Option<Fill>,
+}
+
+impl Backdrop {
+    /// Create the pipelines for targets with the given depth format, if any.
+    pub fn new(
+        target_info: crate::TargetInfo,
+        depth_format: Option<wgpu::TextureFormat>,
+        device: &wgpu::Device,
+    ) -> Self {
+        let shader_module = device.create_shader_module(&wgpu::ShaderModuleDescriptor {
+            label: Some("sky"),
+            source: wgpu::ShaderSource::Wgsl(include_str!("sky.wgsl").into()),
+        });
+
+        let globals_size = mem::size_of::<Globals>() as wgpu::BufferAddress;
+        let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("sky"),
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: wgpu::BufferSize::new(globals_size),
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Sampler {
+                        filtering: true,
+                        comparison: false,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 2,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Texture {
+                        sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                        view_dimension: wgpu::TextureViewDimension::Cube,
+                        multisampled: false,
+                    },
+                    count: None,
+                },
+            ],
+        });
+        let uniform_buf = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("sky globals"),
+            size: globals_size,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+            label: Some("sky sampler"),
+            min_filter: wgpu::FilterMode::Linear,
+            mag_filter: wgpu::FilterMode::Linear,
+            ..Default::default()
+        });
+
+        let gradient_bind_group_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("gradient"),
+                entries: &[wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: wgpu::BufferSize::new(globals_size),
+                    },
+                    count: None,
+                }],
+            });
+        let gradient_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("gradient"),
+            layout: &gradient_bind_group_layout,
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: uniform_buf.as_entire_binding(),
+            }],
+        });
+
+        let create_pipeline = |layout: &wgpu::BindGroupLayout, entry_point: &str| {
+            let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                label: Some("sky"),
+                bind_group_layouts: &[layout],
+                push_constant_ranges: &[],
+            });
+            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                label: Some("sky"),
+                layout: Some(&pipeline_layout),
+                vertex: wgpu::VertexState {
+                    buffers: &[],
+                    module: &shader_module,
+                    entry_point: "main_vs",
+                },
+                primitive: wgpu::PrimitiveState::default(),
+                // the geometry is drawn on top regardless of depth
+                depth_stencil: depth_format.map(|format| wgpu::DepthStencilState {
+                    format,
+                    depth_compare: wgpu::CompareFunction::Always,
+                    depth_write_enabled: false,
+                    bias: Default::default(),
+                    stencil: Default::default(),
+                }),
+                multisample: wgpu::MultisampleState {
+                    count: target_info.sample_count,
+                    ..Default::default()
+                },
+                fragment: Some(wgpu::FragmentState {
+                    targets: &[target_info.format.into()],
+                    module: &shader_module,
+                    entry_point,
+                }),
+            })
+        };
+        let pipeline = create_pipeline(&bind_group_layout, "main_fs");
+        let gradient_pipeline = create_pipeline(&gradient_bind_group_layout, "gradient_fs");
+
+        Self {
+            uniform_buf,
+            sampler,
+            bind_group_layout,
+            bind_group: None,
+            pipeline,
+            gradient_bind_group,
+            gradient_pipeline,
+            fill: None,
+        }
+    }
+
+    /// Upload the view of the camera, returning false if there is
+    /// neither a skybox nor a gradient, so the clear color is enough.
+    pub fn prepare(
+        &mut self,
+        camera: &crate::Camera,
+        camera_space: &bc::RawSpace,
+        aspect: f32,
+        context: &crate::Context,
+    ) -> bool {
+        let (start, end, mode) = match camera.background {
+            bc::Background::Solid(_) => {
+                if camera.skybox.is_none() {
+                    self.fill = None;
+                    return false;
+                }
+                (bc::Color::default(), bc::Color::default(), 0.0)
+            }
+            bc::Background::LinearGradient { top, bottom } => (bottom, top, 0.0),
+            bc::Background::Radial { inner, outer } => (inner, outer, 1.0),
+        };
+        // only the orientation of the camera matters
+        let [x, y, z, w] = camera_space.rot;
+        let m_rot = glam::Mat4::from_quat(glam::Quat::from_xyzw(x, y, z, w));
+        let m_proj = glam::Mat4::from(camera.projection_matrix(aspect));
+        let globals = Globals {
+            inv_view_proj: (m_rot * m_proj.inverse()).to_cols_array_2d(),
+            color_start: start.into_vec4(),
+            color_end: end.into_vec4(),
+            params: [mode, aspect, 0.0, 0.0],
+        };
+        context
+            .queue()
+            .write_buffer(&self.uniform_buf, 0, bytemuck::bytes_of(&globals));
+
+        // the skybox covers the whole screen, so it wins over gradients
+        let skybox = match camera.skybox {
+            Some(skybox) => skybox,
+            None => {
+                self.fill = Some(Fill::Gradient);
+                return true;
+            }
+        };
+        self.fill = Some(Fill::Sky);
+        if self.bind_group.as_ref().map(|&(image, _)| image) != Some(skybox.image) {
+            let bind_group = context
+                .device()
+                .create_bind_group(&wgpu::BindGroupDescriptor {
+                    label: Some("sky"),
+                    layout: &self.bind_group_layout,
+                    entries: &[
+                        wgpu::BindGroupEntry {
+                            binding: 0,
+                            resource: self.uniform_buf.as_entire_binding(),
+                        },
+                        wgpu::BindGroupEntry {
+                            binding: 1,
+                            resource: wgpu::BindingResource::Sampler(&self.sampler),
+                        },
+                        wgpu::BindGroupEntry {
+                            binding: 2,
+                            resource: wgpu::BindingResource::TextureView(
+                                &context.get_image(skybox.image).view,
+                            ),
+                        },
+                    ],
+                });
+            self.bind_group = Some((skybox.image, bind_group));
+        }
+        true
+    }
+
+    /// Draw the skybox or the gradient prepared with [`Backdrop::prepare`].
+    pub fn draw<'a>(&'a self, pass: &mut wgpu::RenderPass<'a>) {
+        match self.fill {
+            Some(Fill::Sky) => {
+                if let Some((_, ref bind_group)) = self.bind_group {
+                    pass.set_pipeline(&self.pipeline);
+                    pass.set_bind_group(0, bind_group, &[]);
+                    pass.draw(0..3, 0..1);
+                }
+            }
+            Some(Fill::Gradient) => {
+                pass.set_pipeline(&self.gradient_pipeline);
+                pass.set_bind_group(0, &self.gradient_bind_group, &[]);
+                pass.draw(0..3, 0..1);
+            }
+            None => {}
+        }
+    }
+}
@@ -0,0 +1,47 @@
+[[block]]
+struct Globals {
+    inv_view_proj: mat4x4<f32>;
+    // gradient colors, from the bottom or the center outwards
+    color_start: vec4<f32>;
+    color_end: vec4<f32>;
+    // x: 0 for linear and 1 for radial, y: aspect ratio
+    params: vec4<f32>;
+};
+[[group(0), binding(0)]]
+var<uniform> globals: Globals;
+[[group(0), binding(1)]]
+var sampler_linear: sampler;
+[[group(0), binding(2)]]
+var sky_cube: texture_cube<f32>;
+
+struct Varyings {
+    [[builtin(position)]] clip_pos: vec4<f32>;
+    [[location(0)]] ndc: vec2<f32>;
+};
+
+[[stage(vertex)]]
+fn main_vs([[builtin(vertex_index)]] index: u32) -> Varyings {
+    // a triangle covering the whole screen
+    let ndc = vec2<f32>(
+        f32(i32(index) / 2) * 4.0 - 1.0,
+        f32(i32(index) & 1) * 4.0 - 1.0,
+    );
+    return Varyings( vec4<f32>(ndc, 1.0, 1.0), ndc );
+}
+
+[[stage(fragment)]]
+fn main_fs(in: Varyings) -> [[location(0)]] vec4<f32> {
+    let point = globals.inv_view_proj * vec4<f32>(in.ndc, 0.5, 1.0);
+    return textureSample(sky_cube, sampler_linear, point.xyz / point.w);
+}
+
+[[stage(fragment)]]
+fn gradient_fs(in: Varyings) -> [[location(0)]] vec4<f32> {
+    var t: f32 = 0.5 * in.ndc.y + 0.5;
+    if (globals.params.x > 0.5) {
+        // keep the rings round, and reach the end color at the corners
+        let scale = vec2<f32>(globals.params.y, 1.0);
+        t = length(in.ndc * scale) / length(scale);
+    }
+    return mix(globals.color_start, globals.color_end, clamp(t, 0.0, 1.0));
+}
@@ -2,7 +2,7 @@ use bc::ContextDetail as _;
 use fxhash::FxHashMap;
 use std::mem;
 
-const DEPTH_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Depth24Plus;
+const DEPTH_FORMAT: wgpu::TextureFormat = bc::Target::DEPTH_FORMAT;
 
 #[repr(C)]
 #[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
@@ -10,40 +10,142 @@ struct Globals {
     view_proj: [[f32; 4]; 4],
 }
 
+/// Per-instance vertex data, holding the world space of an entity and its color.
 #[repr(C)]
 #[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
-struct Locals {
+struct Instance {
     pos_scale: [f32; 4],
     rot: [f32; 4],
+    scale: [f32; 4],
     color: [f32; 4],
 }
 
-#[derive(Eq, Hash, PartialEq)]
-struct LocalKey {
-    uniform_buf_index: usize,
+impl Instance {
+    const fn layout<const LOCATION: u32>() -> wgpu::VertexBufferLayout<'static> {
+        wgpu::VertexBufferLayout {
+            array_stride: mem::size_of::<Self>() as u64,
+            step_mode: wgpu::VertexStepMode::Instance,
+            attributes: &[
+                wgpu::VertexAttribute {
+                    format: wgpu::VertexFormat::Float32x4,
+                    offset: 0,
+                    shader_location: LOCATION,
+                },
+                wgpu::VertexAttribute {
+                    format: wgpu::VertexFormat::Float32x4,
+                    offset: 16,
+                    shader_location: LOCATION + 1,
+                },
+                wgpu::VertexAttribute {
+                    format: wgpu::VertexFormat::Float32x4,
+                    offset: 32,
+                    shader_location: LOCATION + 2,
+                },
+                wgpu::VertexAttribute {
+                    format: wgpu::VertexFormat::Float32x4,
+                    offset: 48,
+                    shader_location: LOCATION + 3,
+                },
+            ],
+        }
+    }
+}
+
+/// Vertex of the lines accumulated with [`bc::Scene::draw_line`].
+#[repr(C)]
+#[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+struct LineVertex {
+    pos: [f32; 3],
+    color: [f32; 4],
+}
+
+impl LineVertex {
+    const fn layout<const LOCATION: u32>() -> wgpu::VertexBufferLayout<'static> {
+        wgpu::VertexBufferLayout {
+            array_stride: mem::size_of::<Self>() as u64,
+            step_mode: wgpu::VertexStepMode::Vertex,
+            attributes: &[
+                wgpu::VertexAttribute {
+                    format: wgpu::VertexFormat::Float32x3,
+                    offset: 0,
+                    shader_location: LOCATION,
+                },
+                wgpu::VertexAttribute {
+                    format: wgpu::VertexFormat::Float32x4,
+                    offset: 12,
+                    shader_location: LOCATION + 1,
+                },
+            ],
+        }
+    }
+}
+
+/// Marks an entity to be drawn by [`Solid`] as a list of line segments,
+/// with every pair of vertices, or indices, forming one segment.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct LineMesh;
+
+/// Marks an entity to be drawn by [`Solid`] as a list of points,
+/// each covering a single pixel.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct PointMesh;
+
+/// Marks an entity to be drawn by [`Solid`] with only the edges of its triangles.
+///
+/// Needs the device to have `wgpu::Features::POLYGON_MODE_LINE`, which can be
+/// requested with [`bc::ContextBuilder::features`]. Without it, the entity
+/// is drawn filled.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Wireframe;
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+enum Topology {
+    Triangles,
+    Wireframe,
+    Lines,
+    Points,
 }
 
 #[derive(Debug)]
 pub struct SolidConfig {
     pub cull_back_faces: bool,
+    /// Draw all the triangle meshes as wireframes, see [`Wireframe`].
+    pub wireframe: bool,
 }
 
 impl Default for SolidConfig {
     fn default() -> Self {
         Self {
             cull_back_faces: true,
+            wireframe: false,
         }
     }
 }
 
+/// Pass drawing every entity with a single color.
+///
+/// Entities sharing a mesh are drawn together with one instanced draw call.
+/// Meshes are drawn as triangles, unless the entity has a [`LineMesh`] or
+/// a [`PointMesh`] component. Lines added with [`bc::Scene::draw_line`]
+/// are drawn on top of that.
 pub struct Solid {
-    depth_texture: Option<(wgpu::TextureView, wgpu::Extent3d)>,
+    depth: super::DepthFallback,
+    backdrop: super::sky::Backdrop,
     global_uniform_buf: wgpu::Buffer,
     global_bind_group: wgpu::BindGroup,
-    local_bind_group_layout: wgpu::BindGroupLayout,
-    local_bind_groups: FxHashMap<LocalKey, wgpu::BindGroup>,
-    uniform_pool: super::BufferPool,
+    instance_buf: wgpu::Buffer,
+    instance_capacity: usize,
+    line_buf: wgpu::Buffer,
+    line_capacity: usize,
+    batches: FxHashMap<(bc::MeshRef, Topology), Vec<Instance>>,
+    line_vertices: Vec<LineVertex>,
     pipeline: wgpu::RenderPipeline,
+    line_pipeline: wgpu::RenderPipeline,
+    point_pipeline: wgpu::RenderPipeline,
+    immediate_pipeline: wgpu::RenderPipeline,
+    wireframe_pipeline: Option<wgpu::RenderPipeline>,
+    wireframe_all: bool,
+    wireframe_warned: bool,
 }
 
 impl Solid {
@@ -90,67 +192,144 @@ impl Solid {
             }],
         });
 
-        let locals_size = mem::size_of::<Locals>() as wgpu::BufferAddress;
-        let local_bgl = d.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
-            label: Some("solid locals"),
-            entries: &[wgpu::BindGroupLayoutEntry {
-                binding: 0,
-                visibility: wgpu::ShaderStages::VERTEX | wgpu::ShaderStages::FRAGMENT,
-                ty: wgpu::BindingType::Buffer {
-                    ty: wgpu::BufferBindingType::Uniform,
-                    has_dynamic_offset: true,
-                    min_binding_size: wgpu::BufferSize::new(locals_size),
-                },
-                count: None,
-            }],
-        });
-
         let pipeline_layout = d.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
             label: Some("solid"),
-            bind_group_layouts: &[&global_bgl, &local_bgl],
+            bind_group_layouts: &[&global_bgl],
             push_constant_ranges: &[],
         });
-        let pipeline = d.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
-            label: Some("solid"),
-            layout: Some(&pipeline_layout),
-            vertex: wgpu::VertexState {
-                buffers: &[crate::Position::layout::<0>()],
-                module: &shader_module,
-                entry_point: "main_vs",
-            },
-            primitive: wgpu::PrimitiveState {
-                cull_mode: if config.cull_back_faces {
-                    Some(wgpu::Face::Back)
-                } else {
-                    None
+        let create_pipeline = |label: &str,
+                               buffers: &[wgpu::VertexBufferLayout],
+                               entry_point: &str,
+                               topology: wgpu::PrimitiveTopology,
+                               polygon_mode: wgpu::PolygonMode| {
+            d.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                label: Some(label),
+                layout: Some(&pipeline_layout),
+                vertex: wgpu::VertexState {
+                    buffers,
+                    module: &shader_module,
+                    entry_point,
                 },
-                ..Default::default()
-            },
-            depth_stencil: Some(wgpu::DepthStencilState {
-                format: DEPTH_FORMAT,
-                depth_compare: wgpu::CompareFunction::LessEqual,
-                depth_write_enabled: true,
-                bias: Default::default(),
-                stencil: Default::default(),
-            }),
-            multisample: wgpu::MultisampleState::default(),
-            fragment: Some(wgpu::FragmentState {
-                targets: &[target_info.format.into()],
-                module: &shader_module,
-                entry_point: "main_fs",
-            }),
-        });
+                primitive: wgpu::PrimitiveState {
+                    topology,
+                    cull_mode: if config.cull_back_faces
+                        && topology == wgpu::PrimitiveTopology::TriangleList
+                    {
+                        Some(wgpu::Face::Back)
+                    } else {
+                        None
+                    },
+                    polygon_mode,
+                    ..Default::default()
+                },
+                depth_stencil: Some(wgpu::DepthStencilState {
+                    format: DEPTH_FORMAT,
+                    depth_compare: wgpu::CompareFunction::LessEqual,
+                    depth_write_enabled: true,
+                    bias: Default::default(),
+                    stencil: Default::default(),
+                }),
+                multisample: wgpu::MultisampleState {
+                    count: target_info.sample_count,
+                    ..Default::default()
+                },
+                fragment: Some(wgpu::FragmentState {
+                    targets: &[target_info.format.into()],
+                    module: &shader_module,
+                    entry_point: "main_fs",
+                }),
+            })
+        };
+        let mesh_buffers = [crate::Position::layout::<0>(), Instance::layout::<1>()];
+        let pipeline = create_pipeline(
+            "solid",
+            &mesh_buffers,
+            "main_vs",
+            wgpu::PrimitiveTopology::TriangleList,
+            wgpu::PolygonMode::Fill,
+        );
+        let line_pipeline = create_pipeline(
+            "solid lines",
+            &mesh_buffers,
+            "main_vs",
+            wgpu::PrimitiveTopology::LineList,
+            wgpu::PolygonMode::Fill,
+        );
+        let point_pipeline = create_pipeline(
+            "solid points",
+            &mesh_buffers,
+            "main_vs",
+            wgpu::PrimitiveTopology::PointList,
+            wgpu::PolygonMode::Fill,
+        );
+        let immediate_pipeline = create_pipeline(
+            "solid immediate lines",
+            &[LineVertex::layout::<0>()],
+            "immediate_vs",
+            wgpu::PrimitiveTopology::LineList,
+            wgpu::PolygonMode::Fill,
+        );
+        let wireframe_pipeline = if Self::supports_wireframe(context) {
+            Some(create_pipeline(
+                "solid wireframe",
+                &mesh_buffers,
+                "main_vs",
+                wgpu::PrimitiveTopology::TriangleList,
+                wgpu::PolygonMode::Line,
+            ))
+        } else {
+            if config.wireframe {
+                log::error!(
+                    "Wireframe mode needs {:?}, which the device doesn't have. Drawing filled meshes.",
+                    wgpu::Features::POLYGON_MODE_LINE
+                );
+            }
+            None
+        };
 
         Self {
-            depth_texture: None,
+            depth: super::DepthFallback::default(),
+            backdrop: super::sky::Backdrop::new(target_info, Some(bc::Target::DEPTH_FORMAT), d),
             global_uniform_buf,
             global_bind_group,
-            local_bind_group_layout: local_bgl,
-            local_bind_groups: Default::default(),
-            uniform_pool: super::BufferPool::uniform("solid locals", d),
+            instance_buf: Self::create_vertex_buffer::<Instance>("solid instances", 1, d),
+            instance_capacity: 1,
+            line_buf: Self::create_vertex_buffer::<LineVertex>("solid lines", 2, d),
+            line_capacity: 2,
+            batches: Default::default(),
+            line_vertices: Vec::new(),
             pipeline,
+            line_pipeline,
+            point_pipeline,
+            immediate_pipeline,
+            wireframe_all: config.wireframe && wireframe_pipeline.is_some(),
+            wireframe_pipeline,
+            wireframe_warned: config.wireframe,
         }
     }
+
+    /// Whether wireframes can be drawn with the device of the context.
+    pub fn supports_wireframe(context: &crate::Context) -> bool {
+        context
+            .device()
+            .features()
+            .contains(wgpu::Features::POLYGON_MODE_LINE)
+    }
+}
+
+impl Solid {
+    fn create_vertex_buffer<T>(
+        label: &str,
+        capacity: usize,
+        device: &wgpu::Device,
+    ) -> wgpu::Buffer {
+        device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some(label),
+            size: (capacity * mem::size_of::<T>()) as wgpu::BufferAddress,
+            usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        })
+    }
 }
 
 impl bc::Pass for Solid {
@@ -164,26 +343,12 @@ impl bc::Pass for Solid {
         let target = context.get_target(targets[0]);
         let device = context.device();
 
-        let reset_depth = match self.depth_texture {
-            Some((_, size)) => size != target.size,
-            None => true,
-        };
-        if reset_depth {
-            let texture = device.create_texture(&wgpu::TextureDescriptor {
-                label: Some("depth"),
-                dimension: wgpu::TextureDimension::D2,
-                format: DEPTH_FORMAT,
-                size: target.size,
-                sample_count: 1,
-                mip_level_count: 1,
-                usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
-            });
-            let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
-            self.depth_texture = Some((view, target.size));
-        }
+        let depth_view = self.depth.view(target, device);
 
         let nodes = scene.bake();
-        self.uniform_pool.reset();
+        let sky = self
+            .backdrop
+            .prepare(camera, &nodes[camera.node], target.aspect(), context);
         let queue = context.queue();
 
         {
@@ -196,32 +361,90 @@ impl bc::Pass for Solid {
             queue.write_buffer(&self.global_uniform_buf, 0, bytemuck::bytes_of(&globals));
         }
 
-        // pre-create the bind groups so that we don't need to do it on the fly
-        let local_bgl = &self.local_bind_group_layout;
-        let entity_count = scene
-            .world
-            .query::<(&bc::Entity, &bc::Color)>()
+        // group the instances by mesh, keeping the vectors around between frames
+        for instances in self.batches.values_mut() {
+            instances.clear();
+        }
+        for (_, (entity, color, lines, points, wireframe)) in scene
+            .query::<(
+                &bc::Entity,
+                &bc::Color,
+                Option<&LineMesh>,
+                Option<&PointMesh>,
+                Option<&Wireframe>,
+            )>()
             .with::<bc::Vertex<crate::Position>>()
             .iter()
-            .count();
-        let uniform_pool_size = self
-            .uniform_pool
-            .prepare_for_count::<Locals>(entity_count, device);
-        for uniform_buf_index in 0..uniform_pool_size {
-            let key = LocalKey { uniform_buf_index };
-            let binding = self.uniform_pool.binding::<Locals>(uniform_buf_index);
-
-            self.local_bind_groups.entry(key).or_insert_with(|| {
-                device.create_bind_group(&wgpu::BindGroupDescriptor {
-                    label: Some("solid locals"),
-                    layout: local_bgl,
-                    entries: &[wgpu::BindGroupEntry {
-                        binding: 0,
-                        resource: wgpu::BindingResource::Buffer(binding),
-                    }],
-                })
+        {
+            if !nodes.is_visible(entity.node) {
+                continue;
+            }
+            let topology = match (lines, points, wireframe) {
+                (Some(_), _, _) => Topology::Lines,
+                (None, Some(_), _) => Topology::Points,
+                (None, None, Some(_)) => Topology::Wireframe,
+                (None, None, None) if self.wireframe_all => Topology::Wireframe,
+                (None, None, None) => Topology::Triangles,
+            };
+            let space = &nodes[entity.node];
+            let batch = self.batches.entry((entity.mesh, topology)).or_default();
+            batch.push(Instance {
+                pos_scale: space.pos_scale,
+                rot: space.rot,
+                scale: space.scale,
+                color: bc::LinearColor::from(*color).into(),
             });
         }
+        self.batches.retain(|_, instances| !instances.is_empty());
+        if self.wireframe_pipeline.is_none()
+            && !self.wireframe_warned
+            && self.batches.keys().any(|&(_, t)| t == Topology::Wireframe)
+        {
+            log::warn!(
+                "Wireframe entities need {:?}, which the device doesn't have. Drawing them filled.",
+                wgpu::Features::POLYGON_MODE_LINE
+            );
+            self.wireframe_warned = true;
+        }
+
+        let instance_count = self.batches.values().map(Vec::len).sum::<usize>();
+        if instance_count > self.instance_capacity {
+            self.instance_capacity = instance_count.next_power_of_two();
+            self.instance_buf = Self::create_vertex_buffer::<Instance>(
+                "solid instances",
+                self.instance_capacity,
+                device,
+            );
+        }
+        let mut ranges = Vec::with_capacity(self.batches.len());
+        let mut offset = 0;
+        for (&(mesh, topology), instances) in self.batches.iter() {
+            queue.write_buffer(
+                &self.instance_buf,
+                (offset * mem::size_of::<Instance>()) as wgpu::BufferAddress,
+                bytemuck::cast_slice(instances),
+            );
+            let end = offset + instances.len();
+            ranges.push((mesh, topology, offset as u32..end as u32));
+            offset = end;
+        }
+
+        self.line_vertices.clear();
+        for line in scene.lines() {
+            let color = bc::LinearColor::from(line.color).into();
+            for &pos in [line.start, line.end].iter() {
+                self.line_vertices.push(LineVertex {
+                    pos: pos.into(),
+                    color,
+                });
+            }
+        }
+        if self.line_vertices.len() > self.line_capacity {
+            self.line_capacity = self.line_vertices.len().next_power_of_two();
+            self.line_buf =
+                Self::create_vertex_buffer::<LineVertex>("solid lines", self.line_capacity, device);
+        }
+        queue.write_buffer(&self.line_buf, 0, bytemuck::cast_slice(&self.line_vertices));
 
         let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
 
@@ -230,14 +453,14 @@ impl bc::Pass for Solid {
                 label: Some("solid"),
                 color_attachments: &[wgpu::RenderPassColorAttachment {
                     view: &target.view,
-                    resolve_target: None,
+                    resolve_target: target.resolve_target.as_ref(),
                     ops: wgpu::Operations {
-                        load: wgpu::LoadOp::Clear(camera.background.into()),
+                        load: wgpu::LoadOp::Clear(camera.clear_color().into()),
                         store: true,
                     },
                 }],
                 depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
-                    view: &self.depth_texture.as_ref().unwrap().0,
+                    view: depth_view,
                     depth_ops: Some(wgpu::Operations {
                         load: wgpu::LoadOp::Clear(1.0),
                         store: true,
@@ -245,40 +468,38 @@ impl bc::Pass for Solid {
                     stencil_ops: None,
                 }),
             });
-            pass.set_pipeline(&self.pipeline);
+            if sky {
+                self.backdrop.draw(&mut pass);
+            }
             pass.set_bind_group(0, &self.global_bind_group, &[]);
 
-            for (_, (entity, color)) in scene
-                .world
-                .query::<(&bc::Entity, &bc::Color)>()
-                .with::<bc::Vertex<crate::Position>>()
-                .iter()
-            {
-                let space = &nodes[entity.node];
-                let locals = Locals {
-                    pos_scale: space.pos_scale,
-                    rot: space.rot,
-                    color: color.into_vec4_gamma(),
-                };
-                let bl = self.uniform_pool.alloc(&locals, queue);
-
-                let key = LocalKey {
-                    uniform_buf_index: bl.index,
-                };
-                let local_bg = &self.local_bind_groups[&key];
-                pass.set_bind_group(1, local_bg, &[bl.offset]);
-
-                let mesh = context.get_mesh(entity.mesh);
+            for (mesh_ref, topology, instances) in ranges {
+                pass.set_pipeline(match topology {
+                    Topology::Triangles => &self.pipeline,
+                    Topology::Wireframe => {
+                        self.wireframe_pipeline.as_ref().unwrap_or(&self.pipeline)
+                    }
+                    Topology::Lines => &self.line_pipeline,
+                    Topology::Points => &self.point_pipeline,
+                });
+                pass.set_vertex_buffer(1, self.instance_buf.slice(..));
+                let mesh = context.get_mesh(mesh_ref);
                 let pos_vs = mesh.vertex_stream::<crate::Position>().unwrap();
                 pass.set_vertex_buffer(0, mesh.buffer.slice(pos_vs.offset..));
 
                 if let Some(ref is) = mesh.index_stream {
                     pass.set_index_buffer(mesh.buffer.slice(is.offset..), is.format);
-                    pass.draw_indexed(0..is.count, 0, 0..1);
+                    pass.draw_indexed(0..is.count, 0, instances);
                 } else {
-                    pass.draw(0..mesh.vertex_count, 0..1);
+                    pass.draw(0..mesh.vertex_count, instances);
                 }
             }
+
+            if !self.line_vertices.is_empty() {
+                pass.set_pipeline(&self.immediate_pipeline);
+                pass.set_vertex_buffer(0, self.line_buf.slice(..));
+                pass.draw(0..self.line_vertices.len() as u32, 0..1);
+            }
         }
 
         queue.submit(Some(encoder.finish()));
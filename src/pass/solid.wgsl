@@ -2,6 +2,13 @@ struct Vertex {
     [[location(0)]] pos: vec3<f32>;
 };
 
+struct Instance {
+    [[location(1)]] pos_scale: vec4<f32>;
+    [[location(2)]] rot: vec4<f32>;
+    [[location(3)]] scale: vec4<f32>;
+    [[location(4)]] color: vec4<f32>;
+};
+
 [[block]]
 struct Globals {
     view_proj: mat4x4<f32>;
@@ -9,26 +16,30 @@ struct Globals {
 [[group(0), binding(0)]]
 var<uniform> globals: Globals;
 
-[[block]]
-struct Locals {
-    pos_scale: vec4<f32>;
-    rot: vec4<f32>;
-    color: vec4<f32>;
+struct Varyings {
+    [[builtin(position)]] clip_pos: vec4<f32>;
+    [[location(0)]] color: vec4<f32>;
 };
-[[group(1), binding(0)]]
-var<uniform> locals: Locals;
 
 fn qrot(q: vec4<f32>, v: vec3<f32>) -> vec3<f32> {
     return v + 2.0*cross(q.xyz, cross(q.xyz,v) + q.w*v);
 }
 
 [[stage(vertex)]]
-fn main_vs(in: Vertex) -> [[builtin(position)]] vec4<f32> {
-    let world = locals.pos_scale.w * qrot(locals.rot, in.pos) + locals.pos_scale.xyz;
-    return globals.view_proj * vec4<f32>(world, 1.0);
+fn main_vs(in: Vertex, instance: Instance) -> Varyings {
+    let world = qrot(instance.rot, instance.scale.xyz * in.pos) + instance.pos_scale.xyz;
+    return Varyings(globals.view_proj * vec4<f32>(world, 1.0), instance.color);
+}
+
+[[stage(vertex)]]
+fn immediate_vs(
+    [[location(0)]] pos: vec3<f32>,
+    [[location(1)]] color: vec4<f32>,
+) -> Varyings {
+    return Varyings(globals.view_proj * vec4<f32>(pos, 1.0), color);
 }
 
 [[stage(fragment)]]
-fn main_fs() -> [[location(0)]] vec4<f32> {
-    return locals.color;
+fn main_fs(in: Varyings) -> [[location(0)]] vec4<f32> {
+    return in.color;
 }
@@ -62,6 +62,7 @@ pub enum Event {
 }
 
 impl Window {
+    #[allow(clippy::new_ret_no_self)]
     pub fn new() -> WindowBuilder {
         WindowBuilder::default()
     }
@@ -244,3 +245,106 @@ impl WindowBuilder {
         Window { raw, event_loop }
     }
 }
+
+/// Camera controller orbiting around a target point.
+///
+/// Dragging with the left button rotates around the target,
+/// dragging with the right button pans it, and scrolling zooms.
+pub struct OrbitController {
+    pub target: mint::Vector3<f32>,
+    pub distance: f32,
+    /// Rotation around the vertical axis, in radians.
+    pub yaw: f32,
+    /// Rotation above the horizon, in radians. Negative values look down.
+    pub pitch: f32,
+    /// Radians of rotation per pixel of pointer movement.
+    pub rotate_sensitivity: f32,
+    /// Panned distance per pixel of pointer movement, relative to the orbit distance.
+    pub pan_sensitivity: f32,
+    /// Relative change of the distance per scroll step.
+    pub zoom_sensitivity: f32,
+    pub min_distance: f32,
+    pub max_distance: f32,
+    pointer: Option<mint::Vector2<f32>>,
+    rotating: bool,
+    panning: bool,
+}
+
+impl OrbitController {
+    pub fn new(target: impl Into<mint::Vector3<f32>>, distance: f32) -> Self {
+        Self {
+            target: target.into(),
+            distance,
+            yaw: 0.0,
+            pitch: 0.0,
+            rotate_sensitivity: 0.01,
+            pan_sensitivity: 0.002,
+            zoom_sensitivity: 0.1,
+            min_distance: 0.1,
+            max_distance: 1000.0,
+            pointer: None,
+            rotating: false,
+            panning: false,
+        }
+    }
+
+    /// Update the controller state from an event.
+    ///
+    /// Returns true if the camera needs to be moved.
+    pub fn handle_event(&mut self, event: &Event) -> bool {
+        match *event {
+            Event::Click { button, pressed } => {
+                match button {
+                    Button::Left => self.rotating = pressed,
+                    Button::Right => self.panning = pressed,
+                    _ => {}
+                }
+                false
+            }
+            Event::Pointer { position } => {
+                let last = self.pointer.replace(position);
+                let (dx, dy) = match last {
+                    Some(last) if self.rotating || self.panning => {
+                        (position.x - last.x, position.y - last.y)
+                    }
+                    _ => return false,
+                };
+                if self.rotating {
+                    let limit = std::f32::consts::FRAC_PI_2 - 0.01;
+                    self.yaw -= dx * self.rotate_sensitivity;
+                    self.pitch = (self.pitch - dy * self.rotate_sensitivity).clamp(-limit, limit);
+                } else {
+                    let transform = self.transform();
+                    let scale = self.pan_sensitivity * self.distance;
+                    let right = glam::Vec3::from(transform.right());
+                    let up = glam::Vec3::from(transform.up());
+                    self.target =
+                        (glam::Vec3::from(self.target) + (up * dy - right * dx) * scale).into();
+                }
+                true
+            }
+            Event::Scroll { delta } => {
+                self.distance = (self.distance * (-delta.y * self.zoom_sensitivity).exp())
+                    .clamp(self.min_distance, self.max_distance);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Transform of the camera, looking at the target along its -Z axis.
+    pub fn transform(&self) -> bc::Transform {
+        let orientation = bc::Transform::from_euler(self.yaw, self.pitch, 0.0).orientation;
+        let offset = glam::Quat::from(orientation) * glam::Vec3::new(0.0, 0.0, self.distance);
+        bc::Transform {
+            position: (glam::Vec3::from(self.target) + offset).into(),
+            orientation,
+            ..bc::Transform::identity()
+        }
+    }
+
+    /// Move the camera node to match the controller.
+    pub fn update_camera(&self, scene: &mut bc::Scene, camera: &bc::Camera) {
+        scene.set_transform(camera.node, self.transform());
+    }
+}
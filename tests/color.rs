@@ -0,0 +1,612 @@
+use baryon::{Color, ColorParseError};
+
+#[test]
+fn parse_hex() {
+    assert_eq!(Color::from_hex("#ff0000"), Ok(Color::RED));
+    assert_eq!(Color::from_hex("00ff00"), Ok(Color::GREEN));
+    assert_eq!(Color::from_hex("#00f"), Ok(Color::BLUE));
+    assert_eq!(Color::from_hex("#11223344"), Ok(Color(0x44112233)));
+    assert_eq!(
+        Color::from_hex("#12345"),
+        Err(ColorParseError::InvalidLength(5))
+    );
+    assert_eq!(
+        Color::from_hex("#12345g"),
+        Err(ColorParseError::InvalidDigit('g'))
+    );
+}
+
+#[test]
+fn hex_round_trip() {
+    let short: Color = "#F0a".parse().unwrap();
+    assert_eq!(short.to_hex_string(), "#ff00aaff");
+    assert_eq!(short.alpha(), 1.0);
+
+    let translucent = Color::from_hex("#20242C80").unwrap();
+    assert_eq!(translucent.to_hex_string(), "#20242c80");
+    assert_eq!(translucent.alpha(), 128.0 / 255.0);
+    assert_eq!(
+        Color::from_hex(&translucent.to_hex_string()),
+        Ok(translucent)
+    );
+}
+
+#[test]
+fn hex_format() {
+    let color = Color(0x80102030);
+    assert_eq!(color.to_string(), "#10203080");
+    assert_eq!(color.to_hex(false), "#102030");
+    assert_eq!(format!("{:x}", color), "10203080");
+    assert_eq!(format!("{:X}", Color(0xFFABCDEF)), "ABCDEFFF");
+    for &value in &[0u32, 1, 0xFF, 0xFF000000, 0x12345678, u32::MAX] {
+        let color = Color(value);
+        assert_eq!(Color::from_hex(&color.to_hex(true)), Ok(color));
+    }
+}
+
+fn assert_close(a: Color, b: Color, max_step: i32) {
+    for shift in [0, 8, 16, 24] {
+        let x = (a.0 >> shift & 0xFF) as i32;
+        let y = (b.0 >> shift & 0xFF) as i32;
+        assert!((x - y).abs() <= max_step, "{} != {}", a, b);
+    }
+}
+
+#[test]
+fn hsl() {
+    assert_eq!(Color::from_hsl(0.0, 1.0, 0.5, 1.0), Color::RED);
+    assert_eq!(Color::from_hsl(480.0, 1.0, 0.5, 1.0), Color::GREEN);
+    assert_eq!(Color::from_hsl(240.0, 1.0, 0.5, 1.0), Color::BLUE);
+    let gray = Color::from_hsl(123.0, 0.0, 0.5, 1.0);
+    assert_eq!((gray.hue(), gray.saturation()), (0.0, 0.0));
+
+    for r in (0..=255).step_by(15) {
+        for g in (0..=255).step_by(15) {
+            for b in (0..=255).step_by(15) {
+                let color = Color(0xFF000000 | r << 16 | g << 8 | b);
+                let (h, s, l) = (color.hue(), color.saturation(), color.lightness());
+                assert_close(Color::from_hsl(h, s, l, 1.0), color, 1);
+            }
+        }
+    }
+}
+
+#[test]
+fn lerp() {
+    let white = Color(0xFFFFFFFF);
+    assert_eq!(Color::BLACK_OPAQUE.lerp(white, -1.0), Color::BLACK_OPAQUE);
+    assert_eq!(Color::BLACK_OPAQUE.lerp(white, 2.0), white);
+    // the linear midpoint is about 0.735 in sRGB
+    assert_close(Color::BLACK_OPAQUE.lerp(white, 0.5), Color(0xFFBCBCBC), 1);
+    assert_close(
+        Color::BLACK_TRANSPARENT.lerp(Color::BLACK_OPAQUE, 0.5),
+        Color(0x7F000000),
+        1,
+    );
+    assert_close(
+        Color::BLACK_OPAQUE.lerp_srgb(white, 0.5),
+        Color(0xFF7F7F7F),
+        1,
+    );
+    assert_eq!(Color::RED.lerp_srgb(Color::BLUE, 1.5), Color::BLUE);
+}
+
+#[test]
+fn hsv() {
+    assert_eq!(Color::from_hsv(0.0, 1.0, 1.0, 1.0), Color::RED);
+    assert_eq!(Color::from_hsv(360.0, 1.0, 1.0, 1.0), Color::RED);
+    assert_eq!(Color::from_hsv(-240.0, 1.0, 1.0, 1.0), Color::GREEN);
+    assert_eq!(Color::from_hsv(75.0, 0.5, 0.0, 1.0), Color::BLACK_OPAQUE);
+    assert_eq!(Color::BLACK_OPAQUE.to_hsv(), (0.0, 0.0, 0.0));
+    assert_eq!(Color::BLUE.to_hsv(), (240.0, 1.0, 1.0));
+
+    for r in (0..=255).step_by(15) {
+        for g in (0..=255).step_by(15) {
+            for b in (0..=255).step_by(15) {
+                let color = Color(0xFF000000 | r << 16 | g << 8 | b);
+                let (h, s, v) = color.to_hsv();
+                assert_close(Color::from_hsv(h, s, v, 1.0), color, 1);
+            }
+        }
+    }
+}
+
+#[test]
+fn hue_steps() {
+    for i in 0..12 {
+        let hue = i as f32 * 30.0;
+        let (h, s, v, a) = Color::from_hsv(hue, 1.0, 1.0, 1.0).to_hsva();
+        assert!((h - hue).abs() < 0.5, "{} != {}", h, hue);
+        assert_eq!((s, v, a), (1.0, 1.0, 1.0));
+        let (h, s, l, _) = Color::from_hsl(hue, 2.0, 0.5, 1.0).to_hsla();
+        assert!((h - hue).abs() < 0.5, "{} != {}", h, hue);
+        assert!((s - 1.0).abs() < 1e-3 && (l - 0.5).abs() < 1e-2);
+    }
+}
+
+#[test]
+fn linear() {
+    assert_eq!(Color::RED.to_linear(), Color::RED);
+    let gray = Color(0x80BCBCBC);
+    assert_close(gray.to_linear(), Color(0x807F7F7F), 1);
+    assert_close(gray.to_linear().from_linear(), gray, 1);
+}
+
+#[test]
+fn linear_round_trip() {
+    for byte in 0..=255u32 {
+        for &shift in &[0, 8, 16] {
+            let color = Color(0xFF000000 | byte << shift);
+            let linear = color.into_vec4_linear();
+            assert_close(Color::from_vec4_linear(linear), color, 1);
+        }
+    }
+    let wc = Color(0xFFBCBCBC).to_wgpu_linear();
+    assert!((wc.r - 0.5).abs() < 0.01 && wc.a == 1.0);
+}
+
+#[test]
+fn over() {
+    let half_red = Color(0x80FF0000);
+    assert_close(half_red.over(Color::BLUE), Color(0xFF800080), 2);
+    assert_eq!(half_red.over(Color::BLACK_TRANSPARENT), half_red);
+    assert_eq!(Color::GREEN.over(half_red), Color::GREEN);
+    assert_eq!(
+        Color::BLACK_TRANSPARENT.over(Color::BLACK_TRANSPARENT),
+        Color::BLACK_TRANSPARENT
+    );
+}
+
+#[test]
+fn named() {
+    assert_eq!(Color::from_name("rebeccapurple"), Some(Color(0xFF663399)));
+    assert_eq!(Color::from_name("Tomato"), Some(Color(0xFFFF6347)));
+    assert_eq!(Color::from_name("NAVY"), Some(Color(0xFF000080)));
+    assert_eq!(Color::from_name("red"), Some(Color::RED));
+    assert_eq!(Color::from_name("lime"), Some(Color::GREEN));
+    assert_eq!(Color::from_name("blue"), Some(Color::BLUE));
+    assert_eq!(
+        Color::from_name("transparent"),
+        Some(Color::BLACK_TRANSPARENT)
+    );
+    assert_eq!(Color::from_name("blurple"), None);
+}
+
+#[test]
+fn conversions() {
+    let color = Color(0x80102030);
+    assert_eq!(color.to_bytes(), [0x10, 0x20, 0x30, 0x80]);
+    assert_eq!(color.to_rgba_bytes(), [0x10, 0x20, 0x30, 0x80]);
+    assert_eq!(color.to_bgra_bytes(), [0x30, 0x20, 0x10, 0x80]);
+    assert_eq!(Color::from([0x10u8, 0x20, 0x30, 0x80]), color);
+    assert_eq!(<[u8; 4]>::from(color), [0x10, 0x20, 0x30, 0x80]);
+    assert_eq!(Color::from((0x10u8, 0x20, 0x30, 0x80)), color);
+    assert_eq!(<(u8, u8, u8, u8)>::from(color), (0x10, 0x20, 0x30, 0x80));
+    assert_eq!(Color::from([1.0, 0.0, 0.0, 1.0]), Color::RED);
+    assert_eq!(Color::from((0.0, 0.0, 1.0, 1.0)), Color::BLUE);
+    assert_eq!(<[f32; 4]>::from(Color::GREEN), [0.0, 1.0, 0.0, 1.0]);
+    let (r, g, b, a) = Color::RED.into();
+    assert_eq!((r, g, b, a), (1.0, 0.0, 0.0, 1.0));
+
+    let colors = [Color::RED, Color::BLUE];
+    let words: &[u32] = bytemuck::cast_slice(&colors);
+    assert_eq!(words, &[0xFFFF0000, 0xFF0000FF]);
+}
+
+#[test]
+fn with_channel() {
+    let color = Color(0x80102030);
+    assert_eq!(color.with_alpha(1.0), Color(0xFF102030));
+    assert_eq!(color.with_red(2.0), Color(0x80FF2030));
+    assert_eq!(color.with_green(0.0), Color(0x80100030));
+    assert_eq!(color.with_blue(-1.0), Color(0x80102000));
+}
+
+#[test]
+fn channel_order() {
+    assert_eq!(Color::RED.into_vec4(), [1.0, 0.0, 0.0, 1.0]);
+    assert_eq!(Color::GREEN.into_vec4(), [0.0, 1.0, 0.0, 1.0]);
+    assert_eq!(Color::BLUE.into_vec4(), [0.0, 0.0, 1.0, 1.0]);
+    assert_eq!(Color(0x00FFFFFF).alpha(), 0.0);
+    assert_eq!(Color::new(1.0, 0.0, 0.0, 1.0), Color::RED);
+}
+
+#[test]
+fn constants() {
+    const ORANGE: Color = Color::new_u8(0xFF, 0x80, 0x00, 0xFF);
+    assert_eq!(ORANGE, Color(0xFFFF8000));
+    assert_eq!(Color::WHITE.into_vec4(), [1.0; 4]);
+    assert_eq!(Color::YELLOW, Color::from_name("yellow").unwrap());
+    assert_eq!(Color::CYAN, Color::from_name("cyan").unwrap());
+    assert_eq!(Color::MAGENTA, Color::from_name("magenta").unwrap());
+}
+
+#[test]
+fn arithmetic() {
+    let gray = Color(0x80808080);
+    assert_eq!(Color::YELLOW * Color::CYAN, Color::GREEN);
+    assert_close(Color::WHITE * gray, gray, 0);
+    assert_close(Color::WHITE * 0.5, Color(0xFF7F7F7F), 1);
+    assert_eq!(gray * 4.0, Color(0x80FFFFFF));
+    assert_eq!(Color::RED + Color::BLUE, Color::MAGENTA);
+    assert_eq!(Color::WHITE + Color::WHITE, Color::WHITE);
+    assert_eq!(Color::WHITE - Color::RED, Color(0x0000FFFF));
+    assert_eq!(Color::BLUE - Color::WHITE, Color::BLACK_TRANSPARENT);
+
+    let mut color = Color::RED;
+    color += Color::GREEN;
+    assert_eq!(color, Color::YELLOW);
+    color -= Color(0x00FF0000);
+    assert_eq!(color, Color::GREEN);
+    color *= Color::CYAN;
+    assert_eq!(color, Color::GREEN);
+    color *= 0.0;
+    assert_eq!(color, Color::BLACK_OPAQUE);
+}
+
+#[test]
+fn premultiply() {
+    let color = Color(0x80FF8000);
+    assert_close(color.premultiply(), Color(0x80804000), 1);
+    assert_close(color.premultiply().unpremultiply(), color, 2);
+    assert_eq!(Color::WHITE.premultiply(), Color::WHITE);
+    assert_eq!(Color(0x00FFFFFF).unpremultiply(), Color::BLACK_TRANSPARENT);
+    let wc = Color(0x00FFFFFF).to_wgpu_premultiplied();
+    assert_eq!((wc.r, wc.a), (0.0, 0.0));
+}
+
+#[test]
+fn byte_round_trip() {
+    for byte in 0..=255u8 {
+        let value = byte as f32 / 255.0;
+        let color = Color::new(value, value, value, value);
+        assert_eq!(color, Color::new_u8(byte, byte, byte, byte));
+        assert_eq!(color.into_vec4(), [value; 4]);
+        let nudged = Color::new(value - 0.4 / 255.0, value + 0.4 / 255.0, value, value);
+        assert_eq!(nudged, color);
+    }
+}
+
+#[test]
+fn display_parse() {
+    assert_eq!(format!("{:?}", Color::RED), "Color(#ff0000ff)");
+    let constants = [
+        (Color::BLACK_TRANSPARENT, "#00000000"),
+        (Color::BLACK_OPAQUE, "#000000ff"),
+        (Color::RED, "#ff0000ff"),
+        (Color::GREEN, "#00ff00ff"),
+        (Color::BLUE, "#0000ffff"),
+        (Color::WHITE, "#ffffffff"),
+    ];
+    for &(color, text) in constants.iter() {
+        assert_eq!(color.to_string(), text);
+        assert_eq!(text.parse::<Color>(), Ok(color));
+    }
+}
+
+#[test]
+fn packed_layouts() {
+    let color = Color(0x80102030);
+    assert_eq!(Color::from_rgba_u32(0x10203080), color);
+    assert_eq!(Color::from_argb_u32(0x80102030), color);
+    assert_eq!(Color::from_abgr_u32(0x80302010), color);
+    assert_eq!(color.to_rgba_u32(), 0x10203080);
+    assert_eq!(color.to_argb_u32(), 0x80102030);
+    assert_eq!(color.to_abgr_u32(), 0x80302010);
+
+    assert_eq!(Color::RED.red(), 1.0);
+    assert_eq!(Color::RED.blue(), 0.0);
+    let wc = wgpu::Color::from(Color::RED);
+    assert_eq!((wc.r, wc.g, wc.b, wc.a), (1.0, 0.0, 0.0, 1.0));
+}
+
+#[test]
+fn named_constants() {
+    use baryon::color::named;
+    assert_eq!(named::TOMATO, Color(0xFFFF6347));
+    assert_eq!(
+        Color::from_name("CornflowerBlue"),
+        Some(named::CORNFLOWERBLUE)
+    );
+    assert_eq!(named::LIME, Color::GREEN);
+    assert_eq!(named::TRANSPARENT, Color::BLACK_TRANSPARENT);
+}
+
+#[test]
+fn luminance() {
+    assert_eq!(Color::BLACK_OPAQUE.luminance(), 0.0);
+    assert!((Color::WHITE.luminance() - 1.0).abs() < 1e-5);
+    assert!((Color::WHITE.contrast_ratio(Color::BLACK_OPAQUE) - 21.0).abs() < 1e-3);
+    assert_eq!(Color::RED.contrast_ratio(Color::RED), 1.0);
+    // published WCAG values for the common mid-grays on white
+    let ratio = |value: u32| Color(value).contrast_ratio(Color::WHITE);
+    assert!((ratio(0xFF767676) - 4.54).abs() < 0.01);
+    assert!((ratio(0xFF777777) - 4.48).abs() < 0.01);
+    assert!((ratio(0xFF595959) - 7.0).abs() < 0.01);
+    assert_eq!(Color::readable_on(Color::WHITE), Color::BLACK_OPAQUE);
+    assert_eq!(Color::readable_on(Color(0xFF202020)), Color::WHITE);
+    assert_eq!(Color::readable_on(Color::YELLOW), Color::BLACK_OPAQUE);
+    assert_eq!(Color::readable_on(Color::BLUE), Color::WHITE);
+
+    let gray = Color(0x80BCBCBC);
+    assert_eq!(gray.to_grayscale(), gray);
+    let yellow = Color::YELLOW.to_grayscale();
+    assert_eq!((yellow.red(), yellow.alpha()), (yellow.blue(), 1.0));
+    assert!((yellow.luminance() - Color::YELLOW.luminance()).abs() < 0.01);
+}
+
+#[test]
+fn gradient() {
+    use baryon::Gradient;
+    let gradient = Gradient::new(vec![
+        (1.0, Color::BLUE),
+        (-1.0, Color::RED),
+        (0.0, Color::GREEN),
+    ]);
+    assert_eq!(gradient.sample(-5.0), Color::RED);
+    assert_eq!(gradient.sample(-1.0), Color::RED);
+    assert_eq!(gradient.sample(0.0), Color::GREEN);
+    assert_eq!(gradient.sample(0.5), Color(0xFF008080));
+    assert_eq!(gradient.sample(1.0), Color::BLUE);
+    assert_eq!(gradient.sample(5.0), Color::BLUE);
+
+    assert_eq!(Gradient::grayscale().sample(0.5), Color(0xFF808080));
+    let viridis = Gradient::viridis();
+    assert_eq!(viridis.sample(0.0), Color(0xFF440154));
+    assert_eq!(viridis.sample(1.0), Color(0xFFFDE725));
+    assert_eq!(Gradient::turbo().stops().len(), 11);
+}
+
+#[test]
+fn blend() {
+    use baryon::BlendMode;
+    let gray = Color(0xFF808080);
+    assert_eq!(gray.blend(Color::WHITE, BlendMode::Multiply), gray);
+    assert_eq!(
+        gray.blend(Color::BLACK_OPAQUE, BlendMode::Multiply),
+        Color::BLACK_OPAQUE
+    );
+    assert_eq!(gray.blend(Color::BLACK_OPAQUE, BlendMode::Screen), gray);
+    assert_eq!(gray.blend(Color::WHITE, BlendMode::Screen), Color::WHITE);
+    assert_eq!(
+        Color::RED.blend(Color::BLUE, BlendMode::Add),
+        Color::MAGENTA
+    );
+    assert_eq!(
+        Color::WHITE.blend(Color::WHITE, BlendMode::Add),
+        Color::WHITE
+    );
+    assert_eq!(
+        Color::MAGENTA.blend(Color::RED, BlendMode::Subtract),
+        Color::BLUE
+    );
+    assert_eq!(
+        Color::BLACK_OPAQUE.blend(Color::WHITE, BlendMode::Subtract),
+        Color::BLACK_OPAQUE
+    );
+    assert_eq!(Color::WHITE.blend(gray, BlendMode::Overlay), Color::WHITE);
+    assert_eq!(
+        Color::BLACK_OPAQUE.blend(gray, BlendMode::Overlay),
+        Color::BLACK_OPAQUE
+    );
+    // a transparent top layer leaves the base untouched
+    assert_eq!(
+        gray.blend(Color::BLACK_TRANSPARENT, BlendMode::Multiply),
+        gray
+    );
+    let half_white = Color::WHITE.with_alpha(0.5);
+    let result = Color::BLACK_TRANSPARENT.blend(half_white, BlendMode::Add);
+    assert!((result.alpha() - 0.5).abs() < 0.01);
+}
+
+/// Reference values computed by hand with the sRGB transfer function.
+#[test]
+fn blend_reference() {
+    use baryon::BlendMode as Bm;
+    let (base1, top1) = (Color(0xFF8040C0), Color(0xFF40C080));
+    let (base2, top2) = (Color(0xFF336699), Color(0x80FFCC00));
+    let cases = [
+        (Bm::Normal, 0xFF40C080, 0xFFBFA46F),
+        (Bm::Multiply, 0xFF1B2E5F, 0xFF335C6F),
+        (Bm::Screen, 0xFF8AC4D0, 0xFFBFA999),
+        (Bm::Overlay, 0xFF29428B, 0xFF3F6B6F),
+        (Bm::Add, 0xFF8DC8E0, 0xFFBFB099),
+        (Bm::Subtract, 0xFF710097, 0xFF234999),
+        (Bm::Darken, 0xFF404080, 0xFF33666F),
+        (Bm::Lighten, 0xFF80C0C0, 0xFFBFA499),
+    ];
+    for &(mode, expected1, expected2) in cases.iter() {
+        assert_eq!(base1.blend(top1, mode), Color(expected1), "{:?}", mode);
+        assert_eq!(base2.blend(top2, mode), Color(expected2), "{:?}", mode);
+    }
+}
+
+#[test]
+fn adjust() {
+    let base = Color(0xFF3366CC);
+    assert_eq!(base.lighten(1.0), Color::WHITE);
+    assert_eq!(base.lighten(5.0), Color::WHITE);
+    assert_eq!(base.darken(1.0), Color::BLACK_OPAQUE);
+    assert_close(base.rotate_hue(360.0), base, 1);
+    assert_close(base.rotate_hue(-720.0), base, 1);
+    assert_close(Color::RED.rotate_hue(120.0), Color::GREEN, 1);
+    assert!(base.lighten(0.1).lightness() > base.lightness());
+    assert!(base.darken(0.1).lightness() < base.lightness());
+    assert!(base.desaturate(0.2).saturation() < base.saturation());
+    let gray = base.desaturate(1.0);
+    assert_eq!(gray.red(), gray.blue());
+    assert_close(gray.saturate(0.0), gray, 0);
+    assert_eq!(
+        base.with_alpha(0.5).lighten(0.2).alpha(),
+        base.with_alpha(0.5).alpha()
+    );
+}
+
+#[test]
+fn linear_color() {
+    use baryon::LinearColor;
+    let linear = LinearColor::from(Color(0xFF808080));
+    assert!((linear.r - 0.2158).abs() < 1e-3);
+    assert_eq!(linear.a, 1.0);
+    assert_eq!(Color::from(linear), Color(0xFF808080));
+    assert_eq!(Color::from(LinearColor::WHITE), Color::WHITE);
+
+    // HDR values survive arithmetic and get clamped on packing
+    let hdr = LinearColor::new(2.0, 4.0, 0.5, 1.0);
+    assert_eq!((hdr * 0.5).g, 2.0);
+    assert_eq!((hdr + hdr).r, 4.0);
+    assert_eq!((hdr - hdr).b, 0.0);
+    assert_eq!(hdr * LinearColor::WHITE, hdr);
+    assert_eq!(hdr.clamp(), LinearColor::new(1.0, 1.0, 0.5, 1.0));
+    assert_eq!(Color::from(hdr).red(), 1.0);
+    assert_eq!(
+        LinearColor::BLACK.lerp(hdr, 0.5),
+        LinearColor::new(1.0, 2.0, 0.25, 1.0)
+    );
+    assert_eq!(wgpu::Color::from(hdr).g, 4.0);
+    assert_eq!(bytemuck::cast::<_, [f32; 4]>(hdr), hdr.into_vec4());
+}
+
+#[test]
+fn kelvin() {
+    let daylight = Color::from_kelvin(6500.0);
+    assert!(daylight.red() > 0.95 && daylight.green() > 0.9 && daylight.blue() > 0.9);
+    let candle = Color::from_kelvin(2000.0);
+    assert_eq!(candle.red(), 1.0);
+    assert!(candle.green() < 0.6 && candle.blue() < 0.2);
+    let sky = Color::from_kelvin(20000.0);
+    assert!(sky.blue() > sky.red());
+    assert_eq!(Color::from_kelvin(0.0), Color::from_kelvin(1000.0));
+    assert_eq!(Color::from_kelvin(1e9), Color::from_kelvin(40000.0));
+    assert_eq!(Color::from_kelvin(f32::MAX).alpha(), 1.0);
+}
+
+#[test]
+fn oklab() {
+    let [l, a, b] = Color::WHITE.to_oklab();
+    assert!((l - 1.0).abs() < 1e-3 && a.abs() < 1e-3 && b.abs() < 1e-3);
+    let [l, _, _] = Color::BLACK_OPAQUE.to_oklab();
+    assert!(l.abs() < 1e-3);
+    // reference values from the OKLab definition
+    let [l, a, b] = Color::RED.to_oklab();
+    assert!((l - 0.628).abs() < 1e-3 && (a - 0.2249).abs() < 1e-3 && (b - 0.1258).abs() < 1e-3);
+    let [_, chroma, hue] = Color::RED.to_oklch();
+    assert!((chroma - 0.2577).abs() < 1e-3 && (hue - 29.23).abs() < 0.1);
+
+    for r in (0..=255).step_by(51) {
+        for g in (0..=255).step_by(51) {
+            for b in (0..=255).step_by(51) {
+                let color = Color::new_u8(r, g, b, 200);
+                let [l, a, b] = color.to_oklab();
+                assert_close(Color::from_oklab(l, a, b, color.alpha()), color, 1);
+                let [l, c, h] = color.to_oklch();
+                assert_close(Color::from_oklch(l, c, h, color.alpha()), color, 1);
+            }
+        }
+    }
+
+    // out of gamut values are clamped
+    assert_eq!(Color::from_oklch(0.5, 1.0, 0.0, 1.0).alpha(), 1.0);
+    assert_eq!(Color::from_oklab(2.0, 0.0, 0.0, 1.0), Color::WHITE);
+
+    let (from, to) = (Color::BLUE, Color::YELLOW);
+    assert_eq!(from.lerp_oklab(to, 0.0), from);
+    assert_close(from.lerp_oklab(to, 1.0), to, 1);
+    let mid = from.lerp_oklab(to, 0.5).to_oklab()[0];
+    assert!((mid - 0.5 * (from.to_oklab()[0] + to.to_oklab()[0])).abs() < 0.01);
+}
+
+#[test]
+fn from_hash() {
+    // the mapping is stable, so pin down a few values
+    assert_eq!(Color::from_hash(0), Color::from_hsl(0.0, 0.65, 0.55, 1.0));
+    assert_eq!(Color::from_hash(1), Color(0xFF426DD7));
+    assert_eq!(Color::from_hash(42), Color(0xFFD74268));
+    let hues = (0..8)
+        .map(|seed| Color::from_hash(seed).hue())
+        .collect::<Vec<_>>();
+    for (i, a) in hues.iter().enumerate() {
+        for b in hues[i + 1..].iter() {
+            let diff = (a - b).abs();
+            assert!(diff.min(360.0 - diff) > 20.0, "{:?}", hues);
+        }
+    }
+    assert_eq!(Color::from_hash(u64::MAX).alpha(), 1.0);
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn random() {
+    let mut rng = rand::thread_rng();
+    for _ in 0..10 {
+        assert_eq!(Color::random(&mut rng).alpha(), 1.0);
+    }
+}
+
+#[test]
+fn grayscale_invert() {
+    assert_eq!(Color::RED.to_grayscale(), Color(0xFF7F7F7F));
+    assert_eq!(Color::GREEN.to_grayscale(), Color(0xFFDCDCDC));
+    assert_eq!(Color::BLUE.to_grayscale(), Color(0xFF4C4C4C));
+    let base = Color(0xFF3366CC);
+    assert_eq!(base.grayscale_amount(0.0), base);
+    assert_eq!(base.grayscale_amount(1.0), base.to_grayscale());
+    let half = base.grayscale_amount(0.5).saturation();
+    assert!(half > 0.0 && half < base.saturation());
+
+    assert_eq!(Color::RED.invert(), Color::CYAN);
+    assert_eq!(Color(0x80123456).invert(), Color(0x80EDCBA9));
+    assert_eq!(base.invert().invert(), base);
+}
+
+#[test]
+fn distance() {
+    let samples = (0..=255)
+        .step_by(85)
+        .flat_map(|r| (0..=255).step_by(85).map(move |g| (r, g)))
+        .flat_map(|(r, g)| {
+            (0..=255)
+                .step_by(85)
+                .map(move |b| Color::new_u8(r, g, b, 255))
+        })
+        .collect::<Vec<_>>();
+    let black_white = Color::BLACK_OPAQUE.distance(Color::WHITE);
+    assert!((black_white - 100.0).abs() < 0.1);
+    for &a in samples.iter() {
+        assert!(a.distance(a).abs() < 1e-5);
+        assert_eq!(a.distance_rgb(a), 0.0);
+        for &b in samples.iter() {
+            assert!((a.distance(b) - b.distance(a)).abs() < 1e-4);
+            assert_eq!(a.distance_rgb(b), b.distance_rgb(a));
+            assert!(a.distance(b) <= black_white + 1e-3);
+        }
+    }
+    assert!(Color(0xFF808080).distance(Color(0xFF818181)) < 2.0);
+    assert!((Color::BLACK_OPAQUE.distance_rgb(Color::WHITE) - 3f32.sqrt()).abs() < 1e-6);
+}
+
+#[test]
+fn pack() {
+    use wgpu::TextureFormat as Tf;
+    let color = Color(0x80112233);
+    assert_eq!(color.pack(Tf::Rgba8Unorm), [0x11, 0x22, 0x33, 0x80]);
+    assert_eq!(color.pack(Tf::Rgba8UnormSrgb), [0x11, 0x22, 0x33, 0x80]);
+    assert_eq!(color.pack(Tf::Bgra8Unorm), [0x33, 0x22, 0x11, 0x80]);
+    assert_eq!(color.pack(Tf::Bgra8UnormSrgb), [0x33, 0x22, 0x11, 0x80]);
+    for &format in [
+        Tf::Rgba8Unorm,
+        Tf::Rgba8UnormSrgb,
+        Tf::Bgra8Unorm,
+        Tf::Bgra8UnormSrgb,
+    ]
+    .iter()
+    {
+        assert_eq!(Color::unpack(color.pack(format), format), color);
+    }
+}
+
+#[test]
+#[should_panic(expected = "Unable to pack")]
+fn pack_unsupported() {
+    Color::WHITE.pack(wgpu::TextureFormat::R8Unorm);
+}
@@ -0,0 +1,339 @@
+/// Context for the tests that render, or `None` with a note
+/// if the machine has no adapter to run them on.
+#[cfg(any(feature = "image", feature = "obj"))]
+fn offscreen_context() -> Option<baryon::Context> {
+    match pollster::block_on(baryon::Context::init().build_offscreen()) {
+        Ok(context) => Some(context),
+        Err(e) => {
+            println!("Skipping: {}", e);
+            None
+        }
+    }
+}
+
+/// Creating a context without a window should fail gracefully
+/// on machines that have no adapter available.
+#[test]
+fn build_offscreen() {
+    match pollster::block_on(baryon::Context::init().build_offscreen()) {
+        Ok(context) => {
+            let info = context.adapter_info();
+            println!("Running on {} via {:?}", info.name, info.backend);
+            let buffer = context.device().create_buffer(&wgpu::BufferDescriptor {
+                label: Some("custom"),
+                size: 16,
+                usage: wgpu::BufferUsages::COPY_DST,
+                mapped_at_creation: false,
+            });
+            context.queue().write_buffer(&buffer, 0, &[0; 16]);
+        }
+        Err(e) => println!("Skipping: {}", e),
+    }
+}
+
+#[cfg(feature = "image")]
+#[test]
+fn render_to_texture() {
+    let mut context = match offscreen_context() {
+        Some(context) => context,
+        None => return,
+    };
+    let size = wgpu::Extent3d {
+        width: 64,
+        height: 32,
+        depth_or_array_layers: 1,
+    };
+    let mut pass = baryon::pass::Flat::new_offscreen(baryon::TargetInfo::offscreen(size), &context);
+    let scene = baryon::Scene::new();
+    let camera = baryon::Camera {
+        background: baryon::Color::RED.into(),
+        ..Default::default()
+    };
+    let _texture = context.render_to_texture(&mut pass, &scene, &camera, size);
+    // capture goes through render_to_texture, and reads the result back
+    let image = pollster::block_on(context.capture(&mut pass, &scene, &camera, size));
+    assert_eq!(image.get_pixel(32, 16).0, [0xFF, 0, 0, 0xFF]);
+}
+
+#[cfg(feature = "image")]
+#[test]
+fn render_lines() {
+    let mut context = match offscreen_context() {
+        Some(context) => context,
+        None => return,
+    };
+    let size = wgpu::Extent3d {
+        width: 64,
+        height: 32,
+        depth_or_array_layers: 1,
+    };
+    let mut pass = baryon::pass::Solid::new_offscreen(
+        &baryon::pass::SolidConfig::default(),
+        baryon::TargetInfo::offscreen(size),
+        &context,
+    );
+    let prototype = context
+        .add_mesh()
+        .vertex(&[
+            baryon::Position([0.0, 0.0, 0.0]),
+            baryon::Position([1.0, 1.0, 0.0]),
+        ])
+        .build();
+    let mut scene = baryon::Scene::new();
+    scene
+        .add_entity(&prototype)
+        .position([0.0, 0.0, -0.5])
+        .component(baryon::Color::RED)
+        .component(baryon::pass::LineMesh)
+        .build();
+    scene
+        .add_entity(&prototype)
+        .position([0.0, 0.0, -0.5])
+        .component(baryon::Color::BLUE)
+        .component(baryon::pass::PointMesh)
+        .build();
+    for _ in 0..100 {
+        scene.draw_line([0.0, 0.0, -0.5], [-1.0, 0.0, -0.5], baryon::Color::WHITE);
+    }
+    let camera = baryon::Camera {
+        background: baryon::Color::BLACK_OPAQUE.into(),
+        ..Default::default()
+    };
+    let image = pollster::block_on(context.capture(&mut pass, &scene, &camera, size));
+    assert!(image.pixels().any(|p| p.0 == [0xFF, 0, 0, 0xFF]));
+    assert!(image.pixels().any(|p| p.0 == [0xFF, 0xFF, 0xFF, 0xFF]));
+}
+
+#[cfg(feature = "image")]
+#[test]
+fn render_wireframe() {
+    let size = wgpu::Extent3d {
+        width: 64,
+        height: 32,
+        depth_or_array_layers: 1,
+    };
+    let config = baryon::pass::SolidConfig {
+        wireframe: true,
+        ..Default::default()
+    };
+    for &features in [wgpu::Features::POLYGON_MODE_LINE, wgpu::Features::empty()].iter() {
+        let mut context = match pollster::block_on(
+            baryon::Context::init().features(features).build_offscreen(),
+        ) {
+            Ok(context) => context,
+            Err(e) => {
+                println!("Skipping: {}", e);
+                continue;
+            }
+        };
+        let supported = baryon::pass::Solid::supports_wireframe(&context);
+        assert!(supported || features.is_empty());
+        let mut pass = baryon::pass::Solid::new_offscreen(
+            &config,
+            baryon::TargetInfo::offscreen(size),
+            &context,
+        );
+        let prototype =
+            baryon::geometry::Geometry::quad(baryon::geometry::Streams::empty(), 1.0, 1.0)
+                .bake(&mut context);
+        let mut scene = baryon::Scene::new();
+        scene
+            .add_entity(&prototype)
+            .position([0.0, 0.0, -0.5])
+            .component(baryon::Color::RED)
+            .component(baryon::pass::Wireframe)
+            .build();
+        let camera = baryon::Camera {
+            background: baryon::Color::BLACK_OPAQUE.into(),
+            ..Default::default()
+        };
+        // falls back to filled triangles without the feature
+        let image = pollster::block_on(context.capture(&mut pass, &scene, &camera, size));
+        assert!(image.pixels().any(|p| p.0 == [0xFF, 0, 0, 0xFF]));
+        if !supported {
+            assert_eq!(image.get_pixel(32, 16).0, [0xFF, 0, 0, 0xFF]);
+        }
+    }
+}
+
+#[cfg(feature = "image")]
+#[test]
+fn render_skybox() {
+    let mut context = match offscreen_context() {
+        Some(context) => context,
+        None => return,
+    };
+    let size = wgpu::Extent3d {
+        width: 16,
+        height: 16,
+        depth_or_array_layers: 1,
+    };
+    let faces = [
+        [0xFF, 0, 0, 0xFF],
+        [0x80, 0, 0, 0xFF],
+        [0, 0xFF, 0, 0xFF],
+        [0, 0x80, 0, 0xFF],
+        [0, 0, 0xFF, 0xFF],
+        [0, 0, 0x80, 0xFF],
+    ];
+    let skybox = context.add_skybox_from_faces(
+        1,
+        [
+            &faces[0], &faces[1], &faces[2], &faces[3], &faces[4], &faces[5],
+        ],
+    );
+    let mut pass = baryon::pass::Solid::new_offscreen(
+        &baryon::pass::SolidConfig::default(),
+        baryon::TargetInfo::offscreen(size),
+        &context,
+    );
+    let mut scene = baryon::Scene::new();
+    let camera = baryon::Camera {
+        projection: baryon::Projection::Perspective { fov_y: 45.0 },
+        depth: 1.0..10.0,
+        node: scene.add_node().build(),
+        skybox: Some(skybox),
+        ..Default::default()
+    };
+    // the camera looks towards -Z
+    let image = pollster::block_on(context.capture(&mut pass, &scene, &camera, size));
+    assert_eq!(image.get_pixel(8, 8).0, [0, 0, 0x80, 0xFF]);
+}
+
+#[cfg(feature = "image")]
+#[test]
+fn render_gradient() {
+    let mut context = match offscreen_context() {
+        Some(context) => context,
+        None => return,
+    };
+    let size = wgpu::Extent3d {
+        width: 4,
+        height: 16,
+        depth_or_array_layers: 1,
+    };
+    let mut pass = baryon::pass::Flat::new_offscreen(baryon::TargetInfo::offscreen(size), &context);
+    let scene = baryon::Scene::new();
+    let camera = baryon::Camera {
+        background: baryon::Background::LinearGradient {
+            top: baryon::Color::WHITE,
+            bottom: baryon::Color::BLACK_OPAQUE,
+        },
+        ..Default::default()
+    };
+    let image = pollster::block_on(context.capture(&mut pass, &scene, &camera, size));
+    assert!(image.get_pixel(0, 0).0[0] > 0xE0);
+    assert!(image.get_pixel(0, 15).0[0] < 0x20);
+}
+
+#[cfg(feature = "pollster")]
+#[test]
+fn build_blocking() {
+    if let Err(e) = baryon::Context::init().build_offscreen_blocking() {
+        println!("Skipping: {}", e);
+    }
+}
+
+#[cfg(feature = "image")]
+#[test]
+fn capture() {
+    let mut context = match offscreen_context() {
+        Some(context) => context,
+        None => return,
+    };
+    let size = wgpu::Extent3d {
+        width: 100,
+        height: 10,
+        depth_or_array_layers: 1,
+    };
+    let mut pass = baryon::pass::Flat::new_offscreen(baryon::TargetInfo::offscreen(size), &context);
+    let scene = baryon::Scene::new();
+    let camera = baryon::Camera {
+        background: baryon::Color(0xFF203040).into(),
+        ..Default::default()
+    };
+    let image = pollster::block_on(context.capture(&mut pass, &scene, &camera, size));
+    assert_eq!(image.dimensions(), (100, 10));
+    assert_eq!(image.get_pixel(99, 9).0, [0x20, 0x30, 0x40, 0xFF]);
+}
+
+#[cfg(feature = "image")]
+#[test]
+fn load_image_from_bytes() {
+    let mut context = match offscreen_context() {
+        Some(context) => context,
+        None => return,
+    };
+    let path = format!(
+        "{}/examples/assets/pickachu.png",
+        env!("CARGO_MANIFEST_DIR")
+    );
+    let bytes = std::fs::read(&path).unwrap();
+    let color = context
+        .load_image_from_bytes(&bytes, baryon::ColorSpace::Srgb)
+        .unwrap();
+    assert!(context
+        .load_image_from_bytes(&bytes[..16], baryon::ColorSpace::Srgb)
+        .is_err());
+    let data = context.load_image_with(&path, baryon::ColorSpace::Linear);
+    let (color_info, data_info) = (context.get_image_info(color), context.get_image_info(data));
+    assert_eq!(color_info.size, data_info.size);
+    assert_eq!(color_info.format, Some(wgpu::TextureFormat::Rgba8UnormSrgb));
+    assert_eq!(data_info.format, Some(wgpu::TextureFormat::Rgba8Unorm));
+}
+
+#[cfg(feature = "image")]
+#[test]
+fn render_hidden_entity() {
+    let mut context = match offscreen_context() {
+        Some(context) => context,
+        None => return,
+    };
+    let prototype = baryon::geometry::Geometry::quad(baryon::geometry::Streams::empty(), 2.0, 2.0)
+        .bake(&mut context);
+    let mut scene = baryon::Scene::new();
+    let group = scene.add_node().build();
+    scene
+        .add_entity(&prototype)
+        .parent(group)
+        .position([0.0, 0.0, -0.5])
+        .component(baryon::Color::RED)
+        .build();
+    let size = wgpu::Extent3d {
+        width: 16,
+        height: 16,
+        depth_or_array_layers: 1,
+    };
+    let mut pass = baryon::pass::Solid::new_offscreen(
+        &baryon::pass::SolidConfig::default(),
+        baryon::TargetInfo::offscreen(size),
+        &context,
+    );
+    let camera = baryon::Camera {
+        background: baryon::Color::BLACK_OPAQUE.into(),
+        ..Default::default()
+    };
+    let image = pollster::block_on(context.capture(&mut pass, &scene, &camera, size));
+    assert_eq!(image.get_pixel(8, 8).0, [0xFF, 0, 0, 0xFF]);
+    scene.set_node_visible(group, false);
+    let image = pollster::block_on(context.capture(&mut pass, &scene, &camera, size));
+    assert_eq!(image.get_pixel(8, 8).0, [0, 0, 0, 0xFF]);
+}
+
+#[cfg(feature = "obj")]
+#[test]
+fn load_obj_mesh() {
+    let mut context = match offscreen_context() {
+        Some(context) => context,
+        None => return,
+    };
+    let path = format!("{}/examples/assets/car.obj", env!("CARGO_MANIFEST_DIR"));
+    assert!(baryon::asset::load_obj_mesh(&path, &mut context).is_ok());
+
+    match baryon::asset::load_obj_mesh("missing.obj", &mut context) {
+        Err(baryon::asset::ObjMeshError::Io(e)) => {
+            assert_eq!(e.kind(), std::io::ErrorKind::NotFound)
+        }
+        other => panic!("Unexpected {:?}", other.map(|_| ())),
+    }
+}
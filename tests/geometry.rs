@@ -0,0 +1,42 @@
+use baryon::geometry::{Geometry, Streams};
+
+/// Every triangle should be wound counter-clockwise when seen from outside.
+fn check_winding(geometry: &Geometry) {
+    let indices = geometry.indices.as_ref().unwrap();
+    for tri in indices.chunks(3) {
+        let [a, b, c] = [0, 1, 2].map(|i| glam::Vec3::from(geometry.positions[tri[i] as usize].0));
+        let normal = (b - a).cross(c - a);
+        assert!(normal.length() > 0.0, "degenerate triangle {:?}", tri);
+        assert!(normal.dot(a + b + c) > 0.0, "inward triangle {:?}", tri);
+    }
+}
+
+#[test]
+fn uv_sphere() {
+    let sphere = Geometry::uv_sphere(Streams::NORMAL, 2.0, 8, 4);
+    assert_eq!(sphere.positions.len(), 9 * 5);
+    assert_eq!(sphere.indices.as_ref().unwrap().len(), 8 * 3 * 6);
+    for (position, normal) in sphere
+        .positions
+        .iter()
+        .zip(sphere.normals.as_ref().unwrap())
+    {
+        let p = glam::Vec3::from(position.0);
+        assert!((p.length() - 2.0).abs() < 1e-5);
+        assert!((p / 2.0 - glam::Vec3::from(normal.0)).length() < 1e-5);
+    }
+    check_winding(&sphere);
+}
+
+#[test]
+fn quad_and_cube() {
+    let quad = Geometry::quad(Streams::NORMAL, 4.0, 2.0);
+    assert_eq!(quad.positions[2].0, [2.0, 1.0, 0.0]);
+    assert!(Geometry::quad(Streams::empty(), 4.0, 2.0).normals.is_none());
+    let indices = quad.indices.as_ref().unwrap();
+    let [a, b, c] = [0, 1, 2].map(|i| glam::Vec3::from(quad.positions[indices[i] as usize].0));
+    assert!((b - a).cross(c - a).z > 0.0);
+
+    check_winding(&Geometry::cube(Streams::NORMAL, 2.0));
+    check_winding(&Geometry::cube(Streams::empty(), 2.0));
+}
@@ -0,0 +1,21 @@
+#![cfg(feature = "glam")]
+
+use baryon::{Scene, Transform};
+
+#[test]
+fn builder_accepts_glam() {
+    let mut scene = Scene::new();
+    let node = scene
+        .add_node()
+        .position(glam::vec3(1.0, 2.0, 3.0))
+        .orientation(glam::Quat::from_rotation_y(1.0))
+        .non_uniform_scale(glam::Vec3::ONE * 2.0)
+        .build();
+    let transform = scene.transform(node);
+    assert_eq!(transform.position, [1.0, 2.0, 3.0].into());
+
+    let matrix = transform.to_glam();
+    assert_eq!(glam::Mat4::from(transform.to_matrix()), matrix);
+    let back = Transform::from_glam(matrix);
+    assert!((glam::Vec3::from(back.scale) - glam::Vec3::splat(2.0)).length() < 1e-5);
+}
@@ -0,0 +1,41 @@
+#![cfg(feature = "window")]
+
+use baryon::window::{Button, Event, OrbitController};
+
+#[test]
+fn orbit() {
+    let close = |a: mint::Vector3<f32>, b: [f32; 3]| {
+        (glam::Vec3::from(a) - glam::Vec3::from(b)).length() < 1e-4
+    };
+    let mut orbit = OrbitController::new([1.0, 0.0, 0.0], 5.0);
+    let transform = orbit.transform();
+    assert!(close(transform.position, [1.0, 0.0, 5.0]));
+    assert!(close(transform.forward(), [0.0, 0.0, -1.0]));
+
+    // moving without a button pressed does nothing
+    assert!(!orbit.handle_event(&Event::Pointer {
+        position: [10.0, 10.0].into(),
+    }));
+    orbit.handle_event(&Event::Click {
+        button: Button::Left,
+        pressed: true,
+    });
+    orbit.rotate_sensitivity = std::f32::consts::FRAC_PI_2 / 100.0;
+    assert!(orbit.handle_event(&Event::Pointer {
+        position: [-90.0, 10.0].into(),
+    }));
+    // dragging to the left orbits a quarter turn towards +X
+    let transform = orbit.transform();
+    assert!(
+        close(transform.position, [6.0, 0.0, 0.0]),
+        "{:?}",
+        transform
+    );
+    assert!(close(transform.forward(), [-1.0, 0.0, 0.0]));
+
+    orbit.max_distance = 6.0;
+    orbit.handle_event(&Event::Scroll {
+        delta: [0.0, -100.0].into(),
+    });
+    assert_eq!(orbit.distance, 6.0);
+}
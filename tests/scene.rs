@@ -0,0 +1,1031 @@
+use baryon::{Scene, Transform};
+
+fn close(a: impl Into<mint::Vector3<f32>>, b: impl Into<mint::Vector3<f32>>) -> bool {
+    (glam::Vec3::from(a.into()) - glam::Vec3::from(b.into())).length() < 1e-5
+}
+
+#[test]
+fn transform() {
+    let mut scene = Scene::new();
+    let transform = Transform {
+        position: [1.0, 2.0, 3.0].into(),
+        scale: [2.0; 3].into(),
+        orientation: [0.0, 0.0, 1.0, 0.0].into(),
+    };
+    let node = scene.add_node().transform(transform).build();
+    assert_eq!(scene.transform(node), transform);
+
+    let moved = scene
+        .add_node()
+        .transform(Transform::from_position([4.0, 0.0, 0.0]))
+        .scale(3.0)
+        .build();
+    assert_eq!(scene[moved].transform().scale, [3.0; 3].into());
+    assert_eq!(scene[moved].get_position(), [4.0, 0.0, 0.0].into());
+
+    scene[node].set_transform(Transform::identity());
+    assert_eq!(scene.transform(node), Transform::default());
+}
+
+#[test]
+fn non_uniform_scale() {
+    let mut scene = Scene::new();
+    let parent = scene.add_node().non_uniform_scale([2.0, 1.0, 1.0]).build();
+    // rotated by 90 degrees around Z, so the child's X axis points along parent's Y
+    let child = scene
+        .add_node()
+        .parent(parent)
+        .position([1.0, 1.0, 0.0])
+        .orientation_around([0.0, 0.0, 1.0], 90.0)
+        .non_uniform_scale([1.0, 3.0, 1.0])
+        .build();
+    let baked = scene.bake();
+    let space = &baked[child];
+    let close = |a: &[f32], b: &[f32]| a.iter().zip(b).all(|(x, y)| (x - y).abs() < 1e-5);
+    assert!(
+        close(&space.pos_scale, &[2.0, 1.0, 0.0, 6.0]),
+        "{:?}",
+        space
+    );
+    assert!(close(&space.scale[..3], &[1.0, 6.0, 1.0]), "{:?}", space);
+
+    let transform = scene.transform(child);
+    assert_eq!(transform.scale, [1.0, 3.0, 1.0].into());
+    assert_eq!(scene[parent].get_scale(), [2.0, 1.0, 1.0].into());
+}
+
+#[test]
+fn euler() {
+    let close = |a: (f32, f32, f32), b: (f32, f32, f32)| {
+        (a.0 - b.0).abs() < 1e-3 && (a.1 - b.1).abs() < 1e-3 && (a.2 - b.2).abs() < 1e-3
+    };
+    let angles = (30.0, -45.0, 60.0);
+    let transform = Transform::from_euler_deg(angles.0, angles.1, angles.2);
+    assert!(
+        close(transform.to_euler_deg(), angles),
+        "{:?}",
+        transform.to_euler_deg()
+    );
+    let yaw = Transform::from_euler(std::f32::consts::FRAC_PI_2, 0.0, 0.0);
+    let orientation = yaw.orientation;
+    assert!(
+        (orientation.s - 0.5f32.sqrt()).abs() < 1e-5
+            && (orientation.v.y - 0.5f32.sqrt()).abs() < 1e-5
+    );
+
+    for &pitch in [90.0, -90.0].iter() {
+        let (yaw, extracted, roll) = Transform::from_euler_deg(20.0, pitch, 10.0).to_euler_deg();
+        assert!(!yaw.is_nan() && !roll.is_nan());
+        assert!((extracted - pitch).abs() < 0.1, "{}", extracted);
+    }
+
+    let mut scene = Scene::new();
+    let node = scene
+        .add_node()
+        .rotation_euler_deg(10.0, 20.0, 30.0)
+        .build();
+    assert!(close(
+        scene.transform(node).to_euler_deg(),
+        (10.0, 20.0, 30.0)
+    ));
+}
+
+#[test]
+fn look_at() {
+    let rotate = |t: Transform, v: [f32; 3]| -> [f32; 3] {
+        (glam::Quat::from(t.orientation) * glam::Vec3::from(v)).into()
+    };
+    let up = [0.0, 1.0, 0.0];
+    let t = Transform::look_at([0.0; 3], [5.0, 0.0, 0.0], up);
+    assert!(close(rotate(t, [0.0, 0.0, -1.0]), [1.0, 0.0, 0.0]));
+    assert!(close(rotate(t, [0.0, 1.0, 0.0]), [0.0, 1.0, 0.0]));
+
+    // degenerate cases stay finite
+    let same = Transform::look_at([1.0; 3], [1.0; 3], up);
+    assert_eq!(same, Transform::from_position([1.0; 3]));
+    let parallel = Transform::look_at([0.0; 3], [0.0, 3.0, 0.0], up);
+    assert!(close(rotate(parallel, [0.0, 0.0, -1.0]), [0.0, 1.0, 0.0]));
+
+    let mut scene = Scene::new();
+    let node = scene
+        .add_node()
+        .position([0.0, 0.0, 2.0])
+        .look_at([0.0, 0.0, 0.0], up)
+        .build();
+    let t = scene.transform(node);
+    assert!(close(rotate(t, [0.0, 0.0, -1.0]), [0.0, 0.0, -1.0]));
+}
+
+#[test]
+fn world_transform() {
+    let mut scene = Scene::new();
+    let parent = scene
+        .add_node()
+        .position([1.0, 0.0, 0.0])
+        .scale(2.0)
+        .build();
+    let child = scene
+        .add_node()
+        .parent(parent)
+        .position([0.0, 1.0, 0.0])
+        .orientation_around([0.0, 0.0, 1.0], 90.0)
+        .build();
+    let grandchild = scene
+        .add_node()
+        .parent(child)
+        .position([1.0, 0.0, 0.0])
+        .build();
+
+    let world = scene.world_transform(grandchild);
+    let baked = scene.bake();
+    let expected = &baked[grandchild].pos_scale;
+    let close = |a: f32, b: f32| (a - b).abs() < 1e-5;
+    assert!(close(world.position.x, 1.0) && close(world.position.y, 4.0));
+    assert!(close(world.position.x, expected[0]) && close(world.position.y, expected[1]));
+    assert_eq!(world.scale, [2.0; 3].into());
+
+    let matrix = scene.world_matrix(grandchild);
+    assert!(close(matrix.w.x, 1.0) && close(matrix.w.y, 4.0) && close(matrix.w.w, 1.0));
+    assert_eq!(scene.world_transform(parent), scene.transform(parent));
+}
+
+#[test]
+fn to_matrix() {
+    let transform = Transform {
+        position: [1.0, 2.0, 3.0].into(),
+        scale: [2.0, 1.0, 1.0].into(),
+        orientation: glam::Quat::from_rotation_z(std::f32::consts::FRAC_PI_2).into(),
+    };
+    // scale: (1, 1, 0) -> (2, 1, 0), rotate: -> (-1, 2, 0), translate: -> (0, 4, 3)
+    let matrix = glam::Mat4::from(transform.to_matrix());
+    let point = matrix.transform_point3(glam::Vec3::new(1.0, 1.0, 0.0));
+    assert!((point - glam::Vec3::new(0.0, 4.0, 3.0)).length() < 1e-5);
+
+    let bytes = transform.to_matrix_bytes();
+    assert_eq!(&bytes[12..], &[1.0, 2.0, 3.0, 1.0]);
+    assert_eq!(glam::Mat4::from_cols_array(&bytes), matrix);
+}
+
+#[test]
+fn origin_objects_own_nodes() {
+    let mut scene = Scene::new();
+    let parent = scene.add_node().build();
+    let first = scene.add_point_light().parent(parent).build();
+    let second = scene.add_point_light().parent(parent).build();
+
+    let nodes = scene
+        .lights()
+        .filter(|&(lr, _)| lr == first || lr == second)
+        .map(|(_, light)| light.node)
+        .collect::<Vec<_>>();
+    assert_eq!(nodes.len(), 2);
+    assert_ne!(nodes[0], nodes[1]);
+    assert!(!nodes.contains(&parent));
+}
+
+#[test]
+fn from_matrix() {
+    use rand::Rng as _;
+    let mut rng = rand::thread_rng();
+    let close = |a: f32, b: f32| (a - b).abs() < 1e-4;
+    for _ in 0..100 {
+        let axis = glam::Vec3::new(rng.gen(), rng.gen(), rng.gen()) + glam::Vec3::splat(0.1);
+        let transform = Transform {
+            position: [rng.gen_range(-10.0..10.0), rng.gen(), rng.gen()].into(),
+            scale: [
+                rng.gen_range(0.1..5.0),
+                rng.gen_range(0.1..5.0),
+                rng.gen_range(0.1..5.0),
+            ]
+            .into(),
+            orientation: glam::Quat::from_axis_angle(axis.normalize(), rng.gen_range(-3.0..3.0))
+                .into(),
+        };
+        let result = Transform::from_matrix(transform.to_matrix());
+        assert!(close(result.position.x, transform.position.x));
+        assert!(close(result.position.y, transform.position.y));
+        assert!(close(result.position.z, transform.position.z));
+        assert!(close(result.scale.x, transform.scale.x));
+        assert!(close(result.scale.y, transform.scale.y));
+        assert!(close(result.scale.z, transform.scale.z));
+        // `q` and `-q` are the same rotation
+        let dot = glam::Quat::from(result.orientation).dot(transform.orientation.into());
+        assert!(close(dot.abs(), 1.0));
+    }
+
+    let mirror =
+        Transform::from_matrix(glam::Mat4::from_scale(glam::Vec3::new(1.0, -2.0, 1.0)).into());
+    assert_eq!(mirror.scale, [-1.0, 2.0, 1.0].into());
+    let mirrored = glam::Mat4::from(mirror.to_matrix());
+    let point = mirrored.transform_point3(glam::Vec3::new(1.0, 1.0, 1.0));
+    assert!((point - glam::Vec3::new(1.0, -2.0, 1.0)).length() < 1e-5);
+}
+
+#[test]
+fn lerp() {
+    let start = Transform::identity();
+    let end = Transform {
+        position: [2.0, 0.0, 0.0].into(),
+        scale: [3.0, 3.0, 3.0].into(),
+        orientation: glam::Quat::from_rotation_y(std::f32::consts::PI).into(),
+    };
+    let half = start.lerp(&end, 0.5);
+    assert_eq!(half.position, [1.0, 0.0, 0.0].into());
+    assert_eq!(half.scale, [2.0, 2.0, 2.0].into());
+    // both directions are equally short for a half turn
+    let (axis, angle) = glam::Quat::from(half.orientation).to_axis_angle();
+    assert!((axis.y.abs() - 1.0).abs() < 1e-5);
+    assert!((angle - std::f32::consts::FRAC_PI_2).abs() < 1e-5);
+
+    let beyond = start.lerp(&end, 2.0);
+    assert_eq!(beyond.position, [4.0, 0.0, 0.0].into());
+
+    assert_eq!(end.lerp(&end, 0.3), end);
+}
+
+#[test]
+#[should_panic(expected = "out of range")]
+fn parent_out_of_range() {
+    let mut other = Scene::new();
+    other.add_node().build();
+    let foreign = other.add_node().build();
+    Scene::new().add_node().parent(foreign);
+}
+
+#[test]
+fn transform_point() {
+    let transform = Transform {
+        position: [1.0, 0.0, 0.0].into(),
+        scale: [2.0; 3].into(),
+        orientation: glam::Quat::from_rotation_y(std::f32::consts::FRAC_PI_2).into(),
+    };
+    // right-handed: a quarter turn around +Y takes +Z to +X
+    assert!(close(
+        transform.transform_vector([0.0, 0.0, 1.0].into()),
+        [2.0, 0.0, 0.0]
+    ));
+    assert!(close(
+        transform.transform_point([0.0, 0.0, 1.0].into()),
+        [3.0, 0.0, 0.0]
+    ));
+    assert!(close(transform.forward(), [-1.0, 0.0, 0.0]));
+    assert!(close(transform.right(), [0.0, 0.0, -1.0]));
+    assert!(close(transform.up(), [0.0, 1.0, 0.0]));
+
+    let mut scene = Scene::new();
+    let parent = scene.add_node().transform(transform).build();
+    let child = scene
+        .add_node()
+        .parent(parent)
+        .position([0.0, 0.0, 1.0])
+        .build();
+    assert!(close(
+        scene.transform_point(child, [0.0; 3].into()),
+        [3.0, 0.0, 0.0]
+    ));
+    assert!(close(
+        scene.transform_vector(child, [0.0, 0.0, 1.0].into()),
+        [2.0, 0.0, 0.0]
+    ));
+}
+
+#[test]
+fn node_mut() {
+    let mut scene = Scene::new();
+    let node = scene.add_node().build();
+    scene.node_mut(node).position = [0.0, 5.0, 0.0].into();
+    scene.node_mut(node).scale = [2.0; 3].into();
+    assert_eq!(scene.node(node).position, [0.0, 5.0, 0.0].into());
+    assert_eq!(scene.bake()[node].pos_scale, [0.0, 5.0, 0.0, 2.0]);
+}
+
+#[test]
+fn rotate_around() {
+    let quarter = glam::Quat::from_rotation_y(std::f32::consts::FRAC_PI_2);
+
+    let mut scene = Scene::new();
+    let node = scene
+        .add_node()
+        .position([1.0, 0.0, 0.0])
+        .orientation(quarter)
+        .build();
+    scene.rotate_node_around(node, [0.0; 3], quarter);
+    let transform = scene.transform(node);
+    assert!(close(transform.position, [0.0, 0.0, -1.0]));
+    // composed with the existing quarter turn
+    assert!(close(transform.forward(), [0.0, 0.0, 1.0]));
+
+    let moved = Transform::from_position([2.0, 0.0, 0.0]).rotate_around([1.0, 0.0, 0.0], quarter);
+    assert!(close(moved.position, [1.0, 0.0, -1.0]));
+}
+
+#[test]
+fn rotation() {
+    let mut scene = Scene::new();
+    let node = scene
+        .add_node()
+        .rotation_deg([0.0, 2.0, 0.0], 90.0)
+        .rotation([1.0, 0.0, 0.0], std::f32::consts::FRAC_PI_2)
+        .rotation([0.0; 3], 1.0)
+        .build();
+    let transform = scene.transform(node);
+    // +X goes to -Z around Y, then to +Y around X
+    assert!(close(transform.right(), [0.0, 1.0, 0.0]));
+    assert!(close(transform.up(), [0.0, 0.0, 1.0]));
+}
+
+#[test]
+fn remove_node() {
+    let mut scene = Scene::new();
+    let parent = scene.add_node().position([1.0, 0.0, 0.0]).build();
+    let middle = scene
+        .add_node()
+        .parent(parent)
+        .position([0.0, 1.0, 0.0])
+        .scale(2.0)
+        .build();
+    let child = scene
+        .add_node()
+        .parent(middle)
+        .position([0.0, 0.0, 1.0])
+        .build();
+    let before = scene.world_transform(child);
+
+    scene.remove_node(middle);
+    assert_eq!(scene.world_transform(child), before);
+
+    // the free slot is reused, even when the parent comes later
+    let reused = scene.add_node().parent(child).build();
+    assert_eq!(reused, middle);
+    let baked = scene.bake();
+    assert_eq!(baked[reused].pos_scale, [1.0, 1.0, 2.0, 2.0]);
+}
+
+#[test]
+fn inverse() {
+    use rand::Rng as _;
+    let mut rng = rand::thread_rng();
+    let mut random = || {
+        let axis = glam::Vec3::new(rng.gen(), rng.gen(), rng.gen()) + glam::Vec3::splat(0.1);
+        Transform {
+            position: [rng.gen_range(-5.0..5.0), rng.gen(), rng.gen()].into(),
+            scale: [rng.gen_range(0.2..4.0); 3].into(),
+            orientation: glam::Quat::from_axis_angle(axis.normalize(), rng.gen_range(-3.0..3.0))
+                .into(),
+        }
+    };
+    let close = |a: glam::Mat4, b: glam::Mat4| a.abs_diff_eq(b, 1e-4);
+    for _ in 0..100 {
+        let (a, b) = (random(), random());
+        let identity = glam::Mat4::from((a * a.inverse()).to_matrix());
+        assert!(close(identity, glam::Mat4::IDENTITY), "{:?}", identity);
+        let identity = glam::Mat4::from((a.inverse() * a).to_matrix());
+        assert!(close(identity, glam::Mat4::IDENTITY), "{:?}", identity);
+
+        let product = glam::Mat4::from(a.to_matrix()) * glam::Mat4::from(b.to_matrix());
+        assert!(close(glam::Mat4::from((a * b).to_matrix()), product));
+    }
+
+    let flat = Transform::from_scale([2.0, 0.0, 1.0]).inverse();
+    assert_eq!(flat.scale, [0.5, 0.0, 1.0].into());
+}
+
+#[test]
+fn camera_view() {
+    let mut scene = Scene::new();
+    let rig = scene.add_node().position([0.0, 0.0, 5.0]).build();
+    let camera = baryon::Camera {
+        projection: baryon::Projection::Perspective { fov_y: 60.0 },
+        node: scene
+            .add_node()
+            .parent(rig)
+            .position([1.0, 0.0, 0.0])
+            .build(),
+        ..Default::default()
+    };
+    let view = glam::Mat4::from(camera.view_matrix(&scene));
+    let origin = view.transform_point3(glam::Vec3::ZERO);
+    assert!((origin - glam::Vec3::new(-1.0, 0.0, -5.0)).length() < 1e-5);
+
+    let proj = glam::Mat4::from(camera.projection_matrix(2.0));
+    let clip = proj * view * glam::Vec4::new(1.0, 0.0, 0.0, 1.0);
+    assert!(clip.x.abs() < 1e-5 && clip.w > 0.0);
+}
+
+#[test]
+fn orthographic() {
+    let camera = baryon::Camera::orthographic(-2.0, 2.0, -1.0, 1.0, 1.0, 11.0);
+    let proj = glam::Mat4::from(camera.projection_matrix(2.0));
+    // wgpu depth goes from 0 at the near plane to 1 at the far one
+    assert!(close(
+        proj.project_point3(glam::Vec3::new(2.0, 1.0, -1.0)),
+        [1.0, 1.0, 0.0]
+    ));
+    assert!(close(
+        proj.project_point3(glam::Vec3::new(-2.0, -1.0, -11.0)),
+        [-1.0, -1.0, 1.0]
+    ));
+
+    // the bounds are kept even if the target is square
+    let square = glam::Mat4::from(camera.projection_matrix(1.0));
+    assert!(close(
+        square.project_point3(glam::Vec3::new(2.0, 1.0, -1.0)),
+        [1.0, 1.0, 0.0]
+    ));
+    assert!(close(
+        square.project_point3(glam::Vec3::new(0.0, 0.0, -6.0)),
+        [0.0, 0.0, 0.5]
+    ));
+}
+
+#[test]
+fn camera_background() {
+    let mut camera = baryon::Camera {
+        background: baryon::Color::RED.into(),
+        ..Default::default()
+    };
+    assert_eq!(
+        camera.background,
+        baryon::Background::Solid(baryon::Color::RED)
+    );
+    assert_eq!(camera.clear_color(), baryon::Color::RED);
+    camera.background = baryon::Background::LinearGradient {
+        top: baryon::Color::BLUE,
+        bottom: baryon::Color::GREEN,
+    };
+    assert_eq!(camera.clear_color(), baryon::Color::GREEN);
+}
+
+#[test]
+fn resolve_world_transforms() {
+    let mut scene = Scene::new();
+    let parent = scene
+        .add_node()
+        .position([0.0, 1.0, 0.0])
+        .rotation_deg([0.0, 1.0, 0.0], 90.0)
+        .scale(3.0)
+        .build();
+    let child = scene
+        .add_node()
+        .parent(parent)
+        .position([0.0, 0.0, 1.0])
+        .non_uniform_scale([1.0, 2.0, 1.0])
+        .build();
+    let leaf = scene
+        .add_node()
+        .parent(child)
+        .position([0.0, 1.0, 0.0])
+        .build();
+
+    let all = scene.resolve_world_transforms();
+    for &node in [parent, child, leaf].iter() {
+        assert_eq!(all[node.index()], scene.world_transform(node));
+    }
+    let position = glam::Vec3::from(scene.world_position(leaf));
+    assert!(
+        (position - glam::Vec3::new(3.0, 7.0, 0.0)).length() < 1e-5,
+        "{}",
+        position
+    );
+}
+
+#[test]
+fn reuse_nodes() {
+    let mut scene = Scene::new();
+    let parent = scene.add_node().build();
+    for i in 0..10_000 {
+        let node = scene
+            .add_node()
+            .parent(parent)
+            .position([i as f32, 0.0, 0.0])
+            .build();
+        scene.remove_node(node);
+    }
+    assert_eq!(scene.resolve_world_transforms().len(), 3);
+}
+
+#[test]
+#[should_panic(expected = "has to be positive")]
+fn light_range_zero() {
+    let mut scene = Scene::new();
+    scene.add_point_light().range(0.0).build();
+}
+
+#[test]
+#[should_panic(expected = "already removed")]
+fn remove_node_twice() {
+    let mut scene = Scene::new();
+    let node = scene.add_node().build();
+    scene.remove_node(node);
+    scene.remove_node(node);
+}
+
+#[test]
+#[should_panic(expected = "already removed")]
+fn parent_removed_node() {
+    let mut scene = Scene::new();
+    let node = scene.add_node().build();
+    scene.remove_node(node);
+    scene.add_node().parent(node).build();
+}
+
+#[test]
+fn set_parent() {
+    use baryon::{ParentError, ParentMode};
+    let mut scene = Scene::new();
+    let a = scene.add_node().position([1.0, 0.0, 0.0]).build();
+    let b = scene
+        .add_node()
+        .parent(a)
+        .position([0.0, 1.0, 0.0])
+        .rotation_deg([0.0, 0.0, 1.0], 90.0)
+        .scale(2.0)
+        .build();
+    let c = scene.add_node().parent(b).position([1.0, 0.0, 0.0]).build();
+    let world = scene.world_position(c);
+    assert!(close(world, [1.0, 3.0, 0.0]), "{:?}", world);
+
+    assert_eq!(
+        scene.set_parent(a, c, ParentMode::KeepLocal),
+        Err(ParentError::Cycle)
+    );
+    assert_eq!(
+        scene.set_parent(b, b, ParentMode::KeepLocal),
+        Err(ParentError::Cycle)
+    );
+    assert_eq!(
+        scene.set_parent(baryon::NodeRef::default(), a, ParentMode::KeepLocal),
+        Err(ParentError::Root)
+    );
+    let removed = scene.add_node().build();
+    scene.remove_node(removed);
+    assert_eq!(
+        scene.set_parent(c, removed, ParentMode::KeepLocal),
+        Err(ParentError::Missing)
+    );
+    let mut other = Scene::new();
+    let far = (0..10).map(|_| other.add_node().build()).last().unwrap();
+    assert_eq!(
+        scene.set_parent(c, far, ParentMode::KeepLocal),
+        Err(ParentError::Missing)
+    );
+
+    // dropping the leaf into the world keeps it in place
+    scene
+        .set_parent(c, baryon::NodeRef::default(), ParentMode::KeepWorld)
+        .unwrap();
+    assert!(close(scene.world_position(c), [1.0, 3.0, 0.0]));
+    // picking it up by another node keeps its pose
+    scene.set_parent(c, b, ParentMode::KeepWorld).unwrap();
+    assert!(close(scene.world_position(c), [1.0, 3.0, 0.0]));
+    assert!(close(scene.transform(c).position, [1.0, 0.0, 0.0]));
+    // moving it with its local transform makes it jump
+    scene.set_parent(c, a, ParentMode::KeepLocal).unwrap();
+    assert!(close(scene.world_position(c), [2.0, 0.0, 0.0]));
+}
+
+#[test]
+fn children() {
+    let mut scene = Scene::new();
+    let root = baryon::NodeRef::default();
+    let a = scene.add_node().build();
+    let b = scene.add_node().parent(a).build();
+    let c = scene.add_node().build();
+    let d = scene.add_node().parent(a).build();
+    let e = scene.add_node().parent(b).build();
+    assert_eq!(scene.children(root).collect::<Vec<_>>(), [a, c]);
+    assert_eq!(scene.children(a).collect::<Vec<_>>(), [b, d]);
+    assert_eq!(scene.descendants(root).collect::<Vec<_>>(), [a, b, e, d, c]);
+    assert_eq!(scene.descendants(e).count(), 0);
+
+    // moved nodes go to the end of the list
+    scene
+        .set_parent(b, c, baryon::ParentMode::KeepLocal)
+        .unwrap();
+    assert_eq!(scene.children(a).collect::<Vec<_>>(), [d]);
+    assert_eq!(scene.descendants(c).collect::<Vec<_>>(), [b, e]);
+    scene.remove_node(b);
+    assert_eq!(scene.children(c).collect::<Vec<_>>(), [e]);
+    let f = scene.add_node().parent(d).build();
+    assert_eq!(f, b);
+    assert_eq!(scene.descendants(a).collect::<Vec<_>>(), [d, f]);
+}
+
+#[test]
+fn find_node() {
+    let mut scene = Scene::new();
+    let body = scene.add_node().name("Body").build();
+    let left = scene.add_node().parent(body).name("Turret").build();
+    let right = scene.add_node().parent(body).name("Turret").build();
+    assert_eq!(scene.find_node("Body"), Some(body));
+    assert_eq!(scene.node_name(left), Some("Turret"));
+    assert_eq!(scene.find_all("Turret").collect::<Vec<_>>(), [left, right]);
+    assert_eq!(scene.find_node("Wheel"), None);
+
+    scene.remove_node(left);
+    assert_eq!(scene.find_all("Turret").collect::<Vec<_>>(), [right]);
+    let wheel = scene.add_node().name("Wheel").build();
+    assert_eq!(wheel, left);
+    assert_eq!(scene.find_node("Wheel"), Some(wheel));
+    assert_eq!(scene.find_node("Turret"), Some(right));
+    let unnamed = scene.add_node().build();
+    assert_eq!(scene.node_name(unnamed), None);
+}
+
+#[test]
+fn draw_line() {
+    let mut scene = Scene::new();
+    scene.draw_line([0.0, 0.0, 0.0], [1.0, 0.0, 0.0], baryon::Color::RED);
+    scene.draw_line([0.0, 0.0, 0.0], [0.0, 1.0, 0.0], baryon::Color::BLUE);
+    assert_eq!(scene.lines().len(), 2);
+    assert_eq!(scene.lines()[1].end, mint::Vector3::from([0.0, 1.0, 0.0]));
+    assert_eq!(scene.lines()[1].color, baryon::Color::BLUE);
+    scene.clear_lines();
+    assert!(scene.lines().is_empty());
+}
+
+#[test]
+fn instantiate() {
+    let mut prefab = Scene::new();
+    let post = prefab
+        .add_node()
+        .name("Post")
+        .position([0.0, 1.0, 0.0])
+        .build();
+    let lamp = prefab
+        .add_node()
+        .parent(post)
+        .name("Lamp")
+        .position([0.0, 2.0, 0.0])
+        .build();
+    prefab.add_point_light().parent(lamp).intensity(3.0).build();
+
+    let mut scene = Scene::new();
+    scene.add_node().build();
+    let first = scene.instantiate(&prefab, baryon::Transform::from_position([5.0, 0.0, 0.0]));
+    let second = scene.instantiate(&prefab, baryon::Transform::from_position([-5.0, 0.0, 0.0]));
+    let lamps = scene.find_all("Lamp").collect::<Vec<_>>();
+    assert_eq!(lamps.len(), 2);
+    assert!(close(scene.world_position(lamps[0]), [5.0, 3.0, 0.0]));
+    assert!(close(scene.world_position(lamps[1]), [-5.0, 3.0, 0.0]));
+    assert_eq!(scene.lights().count(), 2);
+
+    // moving one copy leaves the other in place
+    scene.set_position(first, [5.0, 0.0, 10.0]);
+    assert!(close(scene.world_position(lamps[0]), [5.0, 3.0, 10.0]));
+    assert!(close(scene.world_position(lamps[1]), [-5.0, 3.0, 0.0]));
+    assert_eq!(scene.descendants(second).count(), 3);
+    assert!(scene
+        .descendants(second)
+        .all(|node| !scene.descendants(first).any(|other| other == node)));
+}
+
+#[test]
+fn visibility() {
+    let mut scene = Scene::new();
+    let parent = scene.add_node().hidden().build();
+    let child = scene.add_node().parent(parent).visible(true).build();
+    let other = scene.add_node().build();
+    assert!(!scene.is_visible(parent));
+    assert!(!scene.is_visible(child));
+    assert!(scene.is_visible(other));
+    let baked = scene.bake();
+    assert!(!baked.is_visible(child));
+    assert!(baked.is_visible(other));
+
+    // showing the parent reveals the whole subtree
+    scene.set_node_visible(parent, true);
+    assert!(scene.is_visible(child));
+    scene.set_node_visible(child, false);
+    assert_eq!(scene.resolve_visibility(), vec![true, true, false, true]);
+}
+
+#[test]
+fn aabb_transformed() {
+    assert_eq!(baryon::Aabb::from_points(Vec::<[f32; 3]>::new()), None);
+    let aabb = baryon::Aabb::from_points(vec![[-1.0, -1.0, -1.0], [1.0, 0.5, 1.0]]).unwrap();
+    assert!(close(aabb.center(), [0.0, -0.25, 0.0]));
+
+    // rotating by 45 degrees around Z grows the box in X and Y
+    let transform = Transform {
+        position: [10.0, 0.0, 0.0].into(),
+        scale: [1.0; 3].into(),
+        orientation: glam::Quat::from_rotation_z(std::f32::consts::FRAC_PI_4).into(),
+    };
+    let unit = baryon::Aabb {
+        min: [-1.0; 3].into(),
+        max: [1.0; 3].into(),
+    };
+    let rotated = unit.transformed(&transform);
+    let s = std::f32::consts::SQRT_2;
+    assert!(close(rotated.min, [10.0 - s, -s, -1.0]));
+    assert!(close(rotated.max, [10.0 + s, s, 1.0]));
+}
+
+#[test]
+fn bounds() {
+    let mut scene = Scene::new();
+    assert_eq!(scene.bounds(), None);
+    let unit = baryon::Aabb {
+        min: [-1.0; 3].into(),
+        max: [1.0; 3].into(),
+    };
+    let spawn = |scene: &mut Scene, position: [f32; 3]| {
+        let node = scene.add_node().position(position).build();
+        scene.world_mut().spawn((
+            baryon::Entity {
+                node,
+                mesh: baryon::MeshRef::default(),
+            },
+            unit,
+        ));
+        node
+    };
+    spawn(&mut scene, [5.0, 0.0, 0.0]);
+    let far = spawn(&mut scene, [0.0, 0.0, -20.0]);
+    let bounds = scene.bounds().unwrap();
+    assert_eq!(bounds.min, [-1.0, -1.0, -21.0].into());
+    assert_eq!(bounds.max, [6.0, 1.0, 1.0].into());
+
+    // hidden entities don't count
+    scene.set_node_visible(far, false);
+    assert_eq!(scene.bounds().unwrap().min, [4.0, -1.0, -1.0].into());
+}
+
+#[test]
+fn query() {
+    let prototype = baryon::Prototype::placeholder();
+    let mut scene = baryon::Scene::new();
+    scene
+        .add_entity(&prototype)
+        .component(baryon::Color::RED)
+        .build();
+    scene.add_entity(&prototype).build();
+
+    for (_, color) in scene.query_mut::<&mut baryon::Color>() {
+        *color = baryon::Color::BLUE;
+    }
+    let colors = scene
+        .query::<(&baryon::Entity, &baryon::Color)>()
+        .iter()
+        .map(|(_, (_, &color))| color)
+        .collect::<Vec<_>>();
+    assert_eq!(colors, [baryon::Color::BLUE]);
+}
+
+#[test]
+fn entity_components() {
+    let prototype = baryon::Prototype::placeholder();
+    let mut scene = baryon::Scene::new();
+    let entity = scene.add_entity(&prototype).build();
+    assert!(scene.get::<baryon::Color>(entity).is_err());
+
+    scene.insert(entity, baryon::Color::RED).unwrap();
+    assert_eq!(
+        *scene.get::<baryon::Color>(entity).unwrap(),
+        baryon::Color::RED
+    );
+    *scene.get_mut::<baryon::Color>(entity).unwrap() = baryon::Color::BLUE;
+    assert_eq!(
+        scene.remove::<baryon::Color>(entity).unwrap(),
+        baryon::Color::BLUE
+    );
+    assert!(scene.get::<baryon::Color>(entity).is_err());
+    assert!(scene.get::<baryon::Entity>(entity).is_ok());
+}
+
+#[test]
+fn entity_transform() {
+    let prototype = baryon::Prototype::placeholder();
+    let mut scene = baryon::Scene::new();
+    let orientation = glam::Quat::from_rotation_x(0.5);
+    let entity = scene
+        .add_entity(&prototype)
+        .position([1.0, 2.0, 3.0])
+        .orientation(orientation)
+        .scale(2.0)
+        .build();
+    let scaled_only = scene.add_entity(&prototype).scale(3.0).build();
+
+    let node = scene.entity_node(entity).unwrap();
+    let transform = scene.transform(node);
+    assert_eq!(transform.position, [1.0, 2.0, 3.0].into());
+    assert_eq!(transform.orientation, orientation.into());
+    assert_eq!(transform.scale, [2.0; 3].into());
+    let node = scene.entity_node(scaled_only).unwrap();
+    assert_ne!(node, baryon::NodeRef::default());
+    assert_eq!(scene.transform(node).scale, [3.0; 3].into());
+}
+
+#[test]
+fn move_entity() {
+    let prototype = baryon::Prototype::placeholder();
+    let mut scene = baryon::Scene::new();
+    let entity = scene.add_entity(&prototype).build();
+    let node = scene.entity_node(entity).unwrap();
+    for frame in 0..10 {
+        scene.set_position(node, [frame as f32, 0.0, 0.0]);
+        assert_eq!(scene.bake()[node].pos_scale[0], frame as f32);
+    }
+    scene.set_scale(node, [2.0; 3]);
+    scene.set_orientation(node, glam::Quat::from_rotation_z(1.0));
+    let transform = scene.transform(node);
+    assert_eq!(transform.scale, [2.0; 3].into());
+    scene.set_transform(node, baryon::Transform::identity());
+    assert_eq!(scene.transform(node), baryon::Transform::identity());
+}
+
+#[test]
+fn origin_entities_move_independently() {
+    let prototype = baryon::Prototype::placeholder();
+    let mut scene = baryon::Scene::new();
+    let first = scene.add_entity(&prototype).build();
+    let second = scene.add_entity(&prototype).build();
+
+    let first_node = scene.entity_node(first).unwrap();
+    let second_node = scene.entity_node(second).unwrap();
+    scene.set_position(first_node, [5.0, 0.0, 0.0]);
+    assert_eq!(
+        scene.transform(second_node).position,
+        [0.0, 0.0, 0.0].into()
+    );
+    assert_eq!(
+        scene.transform(baryon::NodeRef::default()),
+        baryon::Transform::identity()
+    );
+}
+
+#[test]
+fn despawn() {
+    let prototype = baryon::Prototype::placeholder();
+    let mut scene = baryon::Scene::new();
+    for _ in 0..5000 {
+        let entity = scene.add_entity(&prototype).build();
+        scene.despawn(entity).unwrap();
+        assert!(scene.despawn(entity).is_err());
+    }
+    assert_eq!(scene.resolve_world_transforms().len(), 2);
+
+    let holder = scene.add_entity(&prototype).build();
+    let hand = scene.entity_node(holder).unwrap();
+    let held = scene.add_entity(&prototype).parent(hand).build();
+    let other = scene.add_entity(&prototype).build();
+    scene.despawn_recursive(holder).unwrap();
+    assert!(scene.entity_node(held).is_none());
+    assert!(scene.entity_node(other).is_some());
+    assert_eq!(scene.query::<&baryon::Entity>().iter().count(), 1);
+}
+
+#[test]
+fn despawn_lights() {
+    let prototype = baryon::Prototype::placeholder();
+    let mut scene = baryon::Scene::new();
+    let sun = scene.add_directional_light().intensity(2.0).build();
+    let lamp = scene.add_entity(&prototype).build();
+    let lamp_node = scene.entity_node(lamp).unwrap();
+    scene
+        .add_point_light()
+        .parent(lamp_node)
+        .position([0.0, 1.0, 0.0])
+        .build();
+    assert_eq!(scene.lights().count(), 2);
+
+    scene.despawn_recursive(lamp).unwrap();
+    let lights = scene.lights().map(|(light, _)| light).collect::<Vec<_>>();
+    assert_eq!(lights, [sun]);
+    // the freed slots are reused without bringing the light back
+    let moved = scene.add_node().position([5.0, 0.0, 0.0]).build();
+    scene.add_node().parent(moved).build();
+    assert_eq!(scene.lights().count(), 1);
+    assert_eq!(scene.lights[sun].intensity, 2.0);
+
+    let sun_node = scene.lights[sun].node;
+    scene.remove_node(sun_node);
+    assert_eq!(scene.lights().count(), 0);
+}
+
+#[test]
+fn find_entity() {
+    let prototype = baryon::Prototype::placeholder();
+    let mut scene = baryon::Scene::new();
+    let hull = scene.add_entity(&prototype).name("Hull").build();
+    let turret = scene.add_entity(&prototype).name("Turret_L").build();
+    assert_eq!(scene.find_entity("Turret_L"), Some(turret));
+    scene.despawn(turret).unwrap();
+    assert_eq!(scene.find_entity("Turret_L"), None);
+    // the freed slot doesn't keep the old name
+    scene.add_entity(&prototype).build();
+    assert_eq!(scene.find_entity("Turret_L"), None);
+    assert_eq!(scene.find_entity("Hull"), Some(hull));
+}
+
+#[test]
+fn instantiate_entities() {
+    let prototype = baryon::Prototype::placeholder();
+    let mut prefab = baryon::Scene::new();
+    let post = prefab
+        .add_entity(&prototype)
+        .position([0.0, 1.0, 0.0])
+        .build();
+    let post_node = prefab.entity_node(post).unwrap();
+    prefab
+        .add_entity(&prototype)
+        .parent(post_node)
+        .position([0.0, 2.0, 0.0])
+        .component(baryon::Color::RED)
+        .build();
+
+    let mut scene = baryon::Scene::new();
+    let first = scene.instantiate(&prefab, baryon::Transform::identity());
+    let second = scene.instantiate(&prefab, baryon::Transform::identity());
+    scene.set_position(first, [10.0, 0.0, 0.0]);
+
+    let mut lamps = Vec::new();
+    for (_, (entity, &color)) in scene.query::<(&baryon::Entity, &baryon::Color)>().iter() {
+        assert_eq!(color, baryon::Color::RED);
+        assert_eq!(entity.mesh, prototype.reference);
+        lamps.push(glam::Vec3::from(scene.world_position(entity.node)));
+    }
+    lamps.sort_by(|a, b| a.x.partial_cmp(&b.x).unwrap());
+    assert_eq!(
+        lamps,
+        [glam::vec3(0.0, 3.0, 0.0), glam::vec3(10.0, 3.0, 0.0)]
+    );
+    assert_eq!(scene.entities_under(first).count(), 2);
+    assert_eq!(scene.entities_under(second).count(), 2);
+}
+
+#[test]
+fn entities_under() {
+    let prototype = baryon::Prototype::placeholder();
+    let mut scene = baryon::Scene::new();
+    let group = scene.add_node().build();
+    let inner = scene.add_node().parent(group).build();
+    let first = scene.add_entity(&prototype).parent(group).build();
+    let second = scene.add_entity(&prototype).parent(inner).build();
+    let outside = scene.add_entity(&prototype).build();
+
+    let mut under: Vec<_> = scene.entities_under(group).collect();
+    under.sort();
+    let mut expected = vec![first, second];
+    expected.sort();
+    assert_eq!(under, expected);
+    assert_eq!(scene.entities_under(baryon::NodeRef::default()).count(), 3);
+    assert!(!scene
+        .entities_under(inner)
+        .any(|e| e == outside || e == first));
+}
+
+#[test]
+fn entities_under_group() {
+    let prototype = baryon::Prototype::placeholder();
+    let mut scene = baryon::Scene::new();
+    let group = scene.add_node().build();
+    let entity = scene.add_entity(&prototype).parent(group).build();
+    scene.add_entity(&prototype).build();
+    assert_eq!(scene.entities_under(group).collect::<Vec<_>>(), [entity]);
+    assert_eq!(scene.entities_under(baryon::NodeRef::default()).count(), 2);
+}
+
+#[test]
+fn hidden_entity() {
+    let prototype = baryon::Prototype::placeholder();
+    let mut scene = baryon::Scene::new();
+    let group = scene.add_node().build();
+    let entity = scene
+        .add_entity(&prototype)
+        .parent(group)
+        .component(baryon::Color::RED)
+        .build();
+    let node = scene.entity_node(entity).unwrap();
+    scene.set_visible(entity, false);
+    assert!(!scene.is_visible(node));
+    scene.set_visible(entity, true);
+    scene.set_node_visible(group, false);
+    assert!(!scene.is_visible(node));
+    assert!(!scene.bake().is_visible(node));
+}
+
+#[test]
+fn mesh_bounds() {
+    let geometry = baryon::geometry::Geometry::cuboid(
+        baryon::geometry::Streams::empty(),
+        [1.0, 2.0, 3.0].into(),
+    );
+    let aabb = baryon::Aabb {
+        min: [-1.0, -2.0, -3.0].into(),
+        max: [1.0, 2.0, 3.0].into(),
+    };
+    assert_eq!(geometry.bounds(), Some(aabb));
+
+    let mut prototype = baryon::Prototype::placeholder();
+    prototype.bounds = geometry.bounds();
+    let mut scene = baryon::Scene::new();
+    let entity = scene
+        .add_entity(&prototype)
+        .position([1.0, 0.0, 0.0])
+        .build();
+    assert_eq!(*scene.get::<baryon::Aabb>(entity).unwrap(), aabb);
+    let bounds = scene.bounds().unwrap();
+    assert_eq!(bounds.min, [0.0, -2.0, -3.0].into());
+}
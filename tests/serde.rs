@@ -0,0 +1,151 @@
+#![cfg(feature = "serde")]
+
+use baryon::Color;
+
+#[test]
+fn color_json() {
+    let color = Color(0xFF20242C);
+    let text = serde_json::to_string(&color).unwrap();
+    assert_eq!(text, "\"#20242cff\"");
+    assert_eq!(serde_json::from_str::<Color>(&text).unwrap(), color);
+    assert_eq!(serde_json::from_str::<Color>("\"#20242C\"").unwrap(), color);
+
+    assert_eq!(
+        serde_json::from_str::<Color>("[1.0, 0.0, 0.0, 1]").unwrap(),
+        Color::RED
+    );
+    assert!(serde_json::from_str::<Color>("[1.0, 0.0, 0.0]").is_err());
+    assert!(serde_json::from_str::<Color>("[1.0, 0.0, 0.0, 1, 0]").is_err());
+
+    let error = serde_json::from_str::<Color>("\"#20242\"").unwrap_err();
+    assert!(error.to_string().contains("invalid color"), "{}", error);
+}
+
+#[test]
+fn color_binary() {
+    let color = Color(0x80102030);
+    let bytes = bincode::serialize(&color).unwrap();
+    assert_eq!(bytes, 0x80102030u32.to_le_bytes());
+    assert_eq!(bincode::deserialize::<Color>(&bytes).unwrap(), color);
+}
+
+#[test]
+fn scene_round_trip() {
+    use baryon::{NodeRef, Scene, SceneLoadError, SceneRegistry};
+
+    let mut scene = Scene::new();
+    let body = scene
+        .add_node()
+        .name("Body")
+        .position([1.0, 2.0, 3.0])
+        .build();
+    let turret = scene
+        .add_node()
+        .parent(body)
+        .name("Turret")
+        .rotation_deg([0.0, 1.0, 0.0], 90.0)
+        .scale(2.0)
+        .build();
+    let removed = scene.add_node().parent(body).build();
+    scene.remove_node(removed);
+    scene
+        .add_point_light()
+        .parent(turret)
+        .intensity(5.0)
+        .range(10.0)
+        .build();
+    scene.add_directional_light().build();
+    scene.world_mut().spawn((Color::RED, String::from("first")));
+    scene.world_mut().spawn((Color::BLUE,));
+
+    let mut registry = SceneRegistry::new();
+    registry.component::<String>("label");
+    let mut bytes = Vec::new();
+    scene.save(&registry, &mut bytes).unwrap();
+    let loaded = Scene::load(&registry, bytes.as_slice()).unwrap();
+
+    let new_body = loaded.find_node("Body").unwrap();
+    let new_turret = loaded.find_node("Turret").unwrap();
+    assert_eq!(loaded.transform(new_body), scene.transform(body));
+    assert_eq!(loaded.transform(new_turret), scene.transform(turret));
+    assert_eq!(loaded.children(new_body).collect::<Vec<_>>(), [new_turret]);
+    // lights have nodes of their own, and the removed node is gone
+    assert_eq!(loaded.descendants(NodeRef::default()).count(), 4);
+
+    let lights = loaded.lights().map(|(_, l)| l).collect::<Vec<_>>();
+    assert_eq!(lights.len(), 2);
+    assert_eq!(
+        loaded.children(new_turret).collect::<Vec<_>>(),
+        [lights[0].node]
+    );
+    assert_eq!(lights[0].intensity, 5.0);
+    assert_eq!(lights[0].range, 10.0);
+    assert_eq!(lights[1].range, f32::INFINITY);
+
+    let mut colors = loaded
+        .query::<&Color>()
+        .iter()
+        .map(|(_, &c)| c.0)
+        .collect::<Vec<_>>();
+    colors.sort_unstable();
+    let mut expected = vec![Color::RED.0, Color::BLUE.0];
+    expected.sort_unstable();
+    assert_eq!(colors, expected);
+    let labels = loaded
+        .query::<(&Color, &String)>()
+        .iter()
+        .map(|(_, (&c, s))| (c, s.clone()))
+        .collect::<Vec<_>>();
+    assert_eq!(labels, [(Color::RED, "first".to_string())]);
+
+    // components have to be registered to be loaded back
+    match Scene::load(&SceneRegistry::new(), bytes.as_slice()) {
+        Err(SceneLoadError::UnknownComponent(name)) => assert_eq!(name, "label"),
+        other => panic!("Unexpected {:?}", other.map(|_| ())),
+    }
+}
+
+#[test]
+fn scene_meshes() {
+    use baryon::{Scene, SceneRegistry};
+
+    let plane = baryon::Prototype::placeholder();
+    let mut scene = Scene::new();
+    let parent = scene.add_node().position([0.0, 1.0, 0.0]).build();
+    scene
+        .add_entity(&plane)
+        .parent(parent)
+        .name("Floor")
+        .component(Color::RED)
+        .build();
+
+    let mut registry = SceneRegistry::new();
+    registry.mesh("plane", &plane);
+    let mut bytes = Vec::new();
+    scene.save(&registry, &mut bytes).unwrap();
+    let loaded = Scene::load(&registry, bytes.as_slice()).unwrap();
+
+    let floor = loaded.find_entity("Floor").unwrap();
+    assert_eq!(
+        loaded.get::<baryon::Entity>(floor).unwrap().mesh,
+        plane.reference
+    );
+    assert_eq!(*loaded.get::<Color>(floor).unwrap(), Color::RED);
+    let node = loaded.entity_node(floor).unwrap();
+    assert_eq!(loaded.world_position(node), [0.0, 1.0, 0.0].into());
+}
+
+#[test]
+fn scene_light_range() {
+    use baryon::{Scene, SceneLoadError, SceneRegistry};
+
+    let text = r##"{
+        "nodes": [{"parent": 0, "position": [0, 0, 0], "orientation": [0, 0, 0, 1], "scale": [1, 1, 1]}],
+        "lights": [{"node": 0, "kind": "Point", "color": "#ffffffff", "intensity": 1.0, "range": 0.0}],
+        "entities": []
+    }"##;
+    match Scene::load(&SceneRegistry::new(), text.as_bytes()) {
+        Err(SceneLoadError::Parse(e)) => assert!(e.to_string().contains("positive"), "{}", e),
+        other => panic!("Unexpected {:?}", other.map(|_| ())),
+    }
+}